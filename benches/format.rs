@@ -0,0 +1,77 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use rusty_money::{iso, Formatter, Money, Params, Position};
+use std::fmt::Write;
+
+/// The allocating implementation that `Formatter::write_money` replaced, kept as a baseline.
+fn legacy_money(money: &Money<iso::Currency>, params: &Params) -> String {
+    let decimal = match params.rounding {
+        Some(digits) => *money.round(digits, rusty_money::Round::HalfEven).amount(),
+        None => *money.amount(),
+    };
+
+    let raw_amount = format!("{}", decimal);
+    let amount_split: Vec<&str> = raw_amount.split('.').collect();
+    let mut digits = amount_split[0].to_string();
+    digits.retain(|c| c != '-');
+
+    let mut current_position: usize = 0;
+    for position in params.separator_pattern.iter() {
+        current_position += position;
+        if digits.len() > current_position {
+            digits.insert(digits.len() - current_position, params.digit_separator);
+            current_position += 1;
+        }
+    }
+
+    let mut amount = digits;
+    if amount_split.len() == 2 {
+        amount.push(params.exponent_separator);
+        amount += amount_split[1];
+    }
+
+    let mut result = String::new();
+    for position in params.positions.iter() {
+        match position {
+            Position::Amount => result.push_str(&amount),
+            Position::Symbol => result.push_str(params.symbol.unwrap_or("")),
+            Position::Sign if money.is_negative() => result.push('-'),
+            _ => {}
+        }
+    }
+    result
+}
+
+fn format_benchmark(c: &mut Criterion) {
+    let money = Money::from_str("-1234567.891", iso::USD).unwrap();
+    let params = Params {
+        symbol: Some("$"),
+        rounding: Some(2),
+        ..Default::default()
+    };
+
+    let mut group = c.benchmark_group("format");
+    group.bench_function("legacy", |b| {
+        b.iter(|| legacy_money(black_box(&money), black_box(&params)))
+    });
+    group.bench_function("Formatter::money", |b| {
+        b.iter(|| Formatter::money(black_box(&money), black_box(params.clone())))
+    });
+    group.bench_function("Formatter::write_money", |b| {
+        let mut buffer = String::with_capacity(64);
+        b.iter(|| {
+            buffer.clear();
+            Formatter::write_money(&mut buffer, black_box(&money), black_box(&params)).unwrap();
+        })
+    });
+    group.bench_function("Display", |b| {
+        let mut buffer = String::with_capacity(64);
+        b.iter(|| {
+            buffer.clear();
+            write!(buffer, "{}", black_box(&money)).unwrap();
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, format_benchmark);
+criterion_main!(benches);
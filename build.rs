@@ -0,0 +1,25 @@
+//! Generates locales and currency display data from the vendored CLDR snapshot in `data/cldr`,
+//! when the `cldr` feature is enabled.
+
+#[cfg(feature = "cldr")]
+#[path = "build/cldr.rs"]
+mod cldr;
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=build");
+
+    #[cfg(feature = "cldr")]
+    {
+        use std::path::Path;
+        use std::{env, fs};
+
+        println!("cargo:rerun-if-changed=data/cldr");
+        let out = cldr::generate(Path::new("data/cldr"));
+        fs::write(
+            Path::new(&env::var("OUT_DIR").unwrap()).join("cldr.rs"),
+            out,
+        )
+        .unwrap();
+    }
+}
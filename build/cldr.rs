@@ -0,0 +1,336 @@
+//! Reads the CLDR snapshot vendored in `data/cldr` and writes Rust source for its locales and currencies.
+//!
+//! Shared by `build.rs`, which generates `cldr.rs` when the `cldr` feature is enabled, and by
+//! `examples/cldr.rs`, which refreshes the snapshot and generates the built-in locales of `LocalFormat::from_locale`.
+
+use serde_json::{Map, Value};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// The PluralRule of each language, and of the locales whose rule differs from their language.
+const PLURAL_RULES: &[(&str, &str)] = &[
+    ("ar", "Arabic"),
+    ("de", "English"),
+    ("en", "English"),
+    ("es", "Spanish"),
+    ("fa", "Hindi"),
+    ("fi", "English"),
+    ("fr", "French"),
+    ("he", "Hebrew"),
+    ("hi", "Hindi"),
+    ("it", "Italian"),
+    ("ja", "Invariant"),
+    ("ko", "Invariant"),
+    ("nl", "English"),
+    ("pl", "Polish"),
+    ("pt", "French"),
+    ("pt-PT", "Italian"),
+    ("ru", "Russian"),
+    ("sv", "English"),
+    ("zh", "Invariant"),
+];
+
+/// The languages which are written right to left.
+const RIGHT_TO_LEFT: &[&str] = &["ar", "fa", "he", "ur"];
+
+/// The NumberingSystem of each CLDR numbering system id.
+const NUMBERING_SYSTEMS: &[(&str, &str)] = &[
+    ("arab", "ArabicIndic"),
+    ("arabext", "ExtendedArabicIndic"),
+    ("deva", "Devanagari"),
+    ("latn", "Latin"),
+];
+
+/// The PluralCategory of each CLDR plural count.
+const PLURAL_CATEGORIES: &[(&str, &str)] = &[
+    ("zero", "Zero"),
+    ("one", "One"),
+    ("two", "Two"),
+    ("few", "Few"),
+    ("many", "Many"),
+    ("other", "Other"),
+];
+
+/// The contents of `manifest.json`, which lists what the snapshot holds.
+pub struct Manifest {
+    /// The CLDR release the snapshot was extracted from (e.g. 44.0.0).
+    pub version: String,
+    /// The locales built into `Locale`, generated into `LocalFormat::from_locale`.
+    pub built_in: Vec<String>,
+    /// The locales generated as `CldrLocale`s.
+    pub locales: Vec<String>,
+    /// The currencies kept for each locale or language.
+    pub currencies: Vec<(String, Vec<String>)>,
+}
+
+impl Manifest {
+    pub fn read(root: &Path) -> Manifest {
+        let path = root.join("manifest.json");
+        let text = fs::read_to_string(&path)
+            .unwrap_or_else(|e| panic!("{} can't be read: {}", path.display(), e));
+        let json: Value = serde_json::from_str(&text)
+            .unwrap_or_else(|e| panic!("{} is not valid JSON: {}", path.display(), e));
+        let strings = |value: &Value| -> Vec<String> {
+            value
+                .as_array()
+                .expect("The manifest lists tags and codes in arrays")
+                .iter()
+                .map(|v| v.as_str().expect("Tags and codes are strings").to_string())
+                .collect()
+        };
+
+        Manifest {
+            version: string(&json, "cldr").to_string(),
+            built_in: strings(&json["built_in"]),
+            locales: strings(&json["locales"]),
+            currencies: object(&json["currencies"])
+                .iter()
+                .map(|(tag, codes)| (tag.clone(), strings(codes)))
+                .collect(),
+        }
+    }
+}
+
+/// Returns the source of `cldr.rs`, included by `src/cldr.rs`.
+pub fn generate(root: &Path) -> String {
+    let manifest = Manifest::read(root);
+    let main = root.join("main");
+
+    let mut locales = String::new();
+    let mut variants = Vec::new();
+    for tag in manifest.locales.iter() {
+        let numbers = numbers(&main, tag);
+        let (format, pattern) = local_format(tag, &numbers, "                ");
+        let variant = variant(tag);
+        writeln!(
+            locales,
+            "            CldrLocale::{} => (\n                {},\n                {:?},\n            ),",
+            variant, format, pattern
+        )
+        .unwrap();
+        variants.push((variant, tag));
+    }
+
+    let mut built_in = String::new();
+    for tag in manifest.built_in.iter() {
+        let (format, _) = local_format(tag, &numbers(&main, tag), "    ");
+        writeln!(built_in, "    {},", format).unwrap();
+    }
+
+    let mut currencies = Vec::new();
+    for (tag, _) in manifest.currencies.iter() {
+        let data = read(&main, tag, "currencies.json")
+            .unwrap_or_else(|| panic!("{} has no currencies.json", tag));
+        for (code, currency) in object(&data["numbers"]["currencies"]) {
+            currencies.push((tag.to_ascii_lowercase(), code.clone(), currency.clone()));
+        }
+    }
+    currencies.sort_by(|a, b| (&a.0, &a.1).cmp(&(&b.0, &b.1)));
+
+    let mut out = format!(
+        "// Generated by build.rs from data/cldr (CLDR {}), do not edit.\n\n",
+        manifest.version
+    );
+    out += "/// Locales generated from the CLDR snapshot in `data/cldr`.\n";
+    out += "#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]\npub enum CldrLocale {\n";
+    for (variant, tag) in variants.iter() {
+        writeln!(out, "    /// {}\n    {},", tag, variant).unwrap();
+    }
+    out += "}\n\nimpl CldrLocale {\n";
+    out += "    /// Every generated locale, sorted by tag.\n";
+    out += "    pub const ALL: &'static [CldrLocale] = &[\n";
+    for (variant, _) in variants.iter() {
+        writeln!(out, "        CldrLocale::{},", variant).unwrap();
+    }
+    out += "    ];\n\n";
+    out += "    /// Returns the LocalFormat and the CLDR currency pattern (e.g. `¤ #,##0.00`) of the locale.\n";
+    out += "    fn data(&self) -> (LocalFormat, &'static str) {\n        match self {\n";
+    out += &locales;
+    out += "        }\n    }\n}\n\n";
+
+    out += "/// The formats of the built-in locales, to check that `LocalFormat::from_locale` matches the snapshot.\n";
+    out += "#[cfg(test)]\nstatic BUILT_IN: &[LocalFormat] = &[\n";
+    out += &built_in;
+    out += "];\n\n";
+
+    out += "/// CLDR currency data, sorted by lowercase locale tag and currency code.\n";
+    out += "static CURRENCIES: &[(&str, &str, CldrCurrency)] = &[\n";
+    for (tag, code, currency) in currencies.iter() {
+        out += &currency_data(tag, code, currency);
+    }
+    out += "];\n";
+    out
+}
+
+/// Returns the data of a locale in a cldr-json file, if the file exists.
+pub fn read(main: &Path, tag: &str, file: &str) -> Option<Value> {
+    let path = main.join(tag).join(file);
+    let text = fs::read_to_string(&path).ok()?;
+    let mut json: Value = serde_json::from_str(&text)
+        .unwrap_or_else(|e| panic!("{} is not valid JSON: {}", path.display(), e));
+    Some(json["main"][tag].take())
+}
+
+fn numbers(main: &Path, tag: &str) -> Value {
+    let mut data =
+        read(main, tag, "numbers.json").unwrap_or_else(|| panic!("{} has no numbers.json", tag));
+    data["numbers"].take()
+}
+
+fn object(value: &Value) -> &Map<String, Value> {
+    value.as_object().expect("CLDR data should be an object")
+}
+
+fn string<'v>(value: &'v Value, field: &str) -> &'v str {
+    value[field]
+        .as_str()
+        .unwrap_or_else(|| panic!("CLDR data is missing {}", field))
+}
+
+/// Returns the name of the Locale or CldrLocale variant of a tag (e.g. `DeCh` for de-CH).
+pub fn variant(tag: &str) -> String {
+    tag.split('-')
+        .map(|part| {
+            let lower = part.to_ascii_lowercase();
+            lower[..1].to_ascii_uppercase() + &lower[1..]
+        })
+        .collect()
+}
+
+fn lookup<'t>(table: &[(&str, &'t str)], key: &str, kind: &str) -> &'t str {
+    table
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, v)| *v)
+        .unwrap_or_else(|| panic!("Unsupported {}: {}", kind, key))
+}
+
+/// Returns the source of the LocalFormat of a locale, with its fields indented after `indent`, and the
+/// CLDR currency pattern it was derived from.
+pub fn local_format<'n>(tag: &str, numbers: &'n Value, indent: &str) -> (String, &'n str) {
+    let system = string(numbers, "defaultNumberingSystem");
+    let symbols = &numbers[format!("symbols-numberSystem-{}", system)];
+    let pattern = string(
+        &numbers[format!("currencyFormats-numberSystem-{}", system)],
+        "standard",
+    );
+
+    let separator = |fields: &[&str]| {
+        let value = fields
+            .iter()
+            .find_map(|field| symbols[*field].as_str())
+            .unwrap_or_else(|| panic!("{} has no {} symbol", tag, fields[0]));
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c,
+            _ => panic!("{} has a separator longer than a char: {:?}", tag, value),
+        }
+    };
+    let digit_separator = separator(&["currencyGroup", "group"]);
+    let exponent_separator = separator(&["currencyDecimal", "decimal"]);
+
+    let language = tag.split('-').next().unwrap();
+    let plural_rule = PLURAL_RULES
+        .iter()
+        .find(|(t, _)| *t == tag)
+        .map(|(_, rule)| *rule)
+        .unwrap_or_else(|| lookup(PLURAL_RULES, language, "plural rule language"));
+    let numbering_system = lookup(NUMBERING_SYSTEMS, system, "numbering system");
+    let (symbol_first, symbol_spacing, grouping) = parse_pattern(pattern);
+    let direction = if RIGHT_TO_LEFT.contains(&language) {
+        "RightToLeft"
+    } else {
+        "LeftToRight"
+    };
+
+    let format = format!(
+        "LocalFormat {{
+{indent}    name: Cow::Borrowed({tag:?}),
+{indent}    digit_separator: {digit_separator:?},
+{indent}    digit_separator_pattern: Cow::Borrowed(&{grouping:?}),
+{indent}    exponent_separator: {exponent_separator:?},
+{indent}    plural_rule: PluralRule::{plural_rule},
+{indent}    symbol_first: {symbol_first},
+{indent}    symbol_spacing: Spacing::{symbol_spacing},
+{indent}    numbering_system: NumberingSystem::{numbering_system},
+{indent}    negative_style: NegativeStyle::Sign,
+{indent}    direction: Direction::{direction},
+{indent}}}"
+    );
+    (format, pattern)
+}
+
+/// Derives symbol placement, spacing and digit grouping from a currency pattern (e.g. `¤ #,##0.00`).
+fn parse_pattern(pattern: &str) -> (bool, &'static str, Vec<usize>) {
+    // Only the positive pattern matters, without its bidi marks.
+    let positive: String = pattern
+        .split(';')
+        .next()
+        .unwrap()
+        .chars()
+        .filter(|c| !matches!(c, '\u{200e}' | '\u{200f}' | '\u{61c}'))
+        .collect();
+
+    let symbol = positive.find('¤').expect("Currency patterns contain ¤");
+    let number_start = positive.find(['#', '0']).expect("Patterns contain digits");
+    let number_end = positive.rfind(['#', '0']).unwrap() + 1;
+
+    let symbol_first = symbol < number_start;
+    let spacing = if symbol_first {
+        &positive[symbol + '¤'.len_utf8()..number_start]
+    } else {
+        &positive[number_end..symbol]
+    };
+    let spacing = match spacing {
+        "" => "None",
+        " " => "Regular",
+        "\u{a0}" => "NoBreak",
+        "\u{202f}" => "NarrowNoBreak",
+        _ => panic!("Unsupported spacing in currency pattern {:?}", pattern),
+    };
+
+    // #,##,##0.00 has a primary group of 3, then secondary groups of 2, which `Formatter` repeats.
+    let integer = positive[number_start..number_end]
+        .split('.')
+        .next()
+        .unwrap();
+    let groups: Vec<usize> = integer.split(',').map(str::len).collect();
+    let primary = groups[groups.len() - 1];
+    let secondary = if groups.len() > 2 {
+        groups[groups.len() - 2]
+    } else {
+        primary
+    };
+
+    let grouping = if secondary == primary {
+        vec![primary]
+    } else {
+        vec![primary, secondary]
+    };
+    (symbol_first, spacing, grouping)
+}
+
+/// Returns the entry of the currency table for a currency in a locale.
+fn currency_data(tag: &str, code: &str, currency: &Value) -> String {
+    let mut plural_names = String::new();
+    for (count, category) in PLURAL_CATEGORIES {
+        if let Some(name) = currency[format!("displayName-count-{}", count)].as_str() {
+            write!(plural_names, "(PluralCategory::{}, {:?}), ", category, name).unwrap();
+        }
+    }
+
+    let symbol = string(currency, "symbol");
+    format!(
+        "    ({tag:?}, {code:?}, CldrCurrency {{
+        symbol: {symbol:?},
+        narrow_symbol: {narrow_symbol:?},
+        display_name: {display_name:?},
+        plural_names: &[{plural_names}],
+    }}),
+",
+        narrow_symbol = currency["symbol-alt-narrow"].as_str().unwrap_or(symbol),
+        display_name = string(currency, "displayName"),
+        plural_names = plural_names.trim_end_matches(", "),
+    )
+}
@@ -0,0 +1,142 @@
+//! Refreshes the CLDR snapshot in `data/cldr` from a checkout of cldr-json, and generates the built-in locales of
+//! `LocalFormat::from_locale` in `src/locale.rs` from it.
+//!
+//! `data/cldr/manifest.json` lists the locales and currencies to extract. Given the path of a cldr-json checkout
+//! (https://github.com/unicode-org/cldr-json), their files are copied from `cldr-numbers-full` with only the
+//! fields `build.rs` reads, and the manifest records the CLDR version. Without a path, only the built-in locales
+//! are generated from the current snapshot.
+//!
+//! Run with `cargo run --example cldr --features cldr -- [path to cldr-json]`.
+
+#[path = "../build/cldr.rs"]
+#[allow(dead_code)]
+mod cldr;
+
+use serde_json::{json, Map, Value};
+use std::path::Path;
+use std::{env, fs};
+
+/// The fields of `symbols-numberSystem-*` which locales need.
+const SYMBOLS: &[&str] = &["currencyDecimal", "currencyGroup", "decimal", "group"];
+
+const START: &str = "            // Generated from data/cldr by `cargo run --example cldr --features cldr`, do not edit.\n";
+const END: &str = "            // End of the generated locales.\n";
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let data = root.join("data/cldr");
+    if let Some(source) = env::args().nth(1) {
+        extract(&data, &Path::new(&source).join("cldr-numbers-full"));
+    }
+
+    let manifest = cldr::Manifest::read(&data);
+    let mut arms = String::new();
+    for tag in manifest.built_in.iter() {
+        let numbers = &cldr::read(&data.join("main"), tag, "numbers.json").unwrap()["numbers"];
+        let (format, _) = cldr::local_format(tag, numbers, "            ");
+        arms += &format!("            {} => {},\n", cldr::variant(tag), format);
+    }
+
+    let path = root.join("src/locale.rs");
+    let source = fs::read_to_string(&path).unwrap();
+    let start = source
+        .find(START)
+        .expect("The generated locales start with a comment")
+        + START.len();
+    let end = start
+        + source[start..]
+            .find(END)
+            .expect("The generated locales end with a comment");
+    fs::write(
+        &path,
+        format!("{}{}{}", &source[..start], arms, &source[end..]),
+    )
+    .unwrap();
+}
+
+/// Copies the locales and currencies of the manifest from `cldr-numbers-full`, keeping only what `build.rs` reads.
+fn extract(data: &Path, source: &Path) {
+    let path = data.join("manifest.json");
+    let mut manifest: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    let package: Value =
+        serde_json::from_str(&fs::read_to_string(source.join("package.json")).unwrap()).unwrap();
+    manifest["cldr"] = package["version"].clone();
+    write(&path, &manifest);
+
+    let manifest = cldr::Manifest::read(data);
+    for tag in manifest.built_in.iter().chain(manifest.locales.iter()) {
+        let numbers = &source_data(source, tag, "numbers.json")["numbers"];
+        let system = numbers["defaultNumberingSystem"].as_str().unwrap();
+        let symbols_key = format!("symbols-numberSystem-{}", system);
+        let formats_key = format!("currencyFormats-numberSystem-{}", system);
+
+        let mut symbols = Map::new();
+        for field in SYMBOLS {
+            if let Some(value) = numbers[&symbols_key].get(*field) {
+                symbols.insert(field.to_string(), value.clone());
+            }
+        }
+        let standard = numbers[&formats_key]["standard"].clone();
+        let trimmed = json!({
+            "defaultNumberingSystem": system,
+            symbols_key: symbols,
+            formats_key: { "standard": standard },
+        });
+        write_locale(data, tag, "numbers.json", trimmed);
+    }
+
+    for (tag, codes) in manifest.currencies.iter() {
+        let currencies = &source_data(source, tag, "currencies.json")["numbers"]["currencies"];
+        let mut trimmed = Map::new();
+        for code in codes {
+            let currency = currencies[code]
+                .as_object()
+                .unwrap_or_else(|| panic!("{} has no data for {}", tag, code));
+            let fields: Map<String, Value> = currency
+                .iter()
+                .filter(|(field, _)| {
+                    matches!(
+                        field.as_str(),
+                        "displayName" | "symbol" | "symbol-alt-narrow"
+                    ) || field.starts_with("displayName-count-")
+                })
+                .map(|(field, value)| (field.clone(), value.clone()))
+                .collect();
+            trimmed.insert(code.clone(), Value::Object(fields));
+        }
+        write_locale(
+            data,
+            tag,
+            "currencies.json",
+            json!({ "currencies": trimmed }),
+        );
+    }
+}
+
+/// Returns the data of a locale in cldr-json. Default content locales (e.g. fr-FR) have no directory of their own
+/// and use the data of their language.
+fn source_data(source: &Path, tag: &str, file: &str) -> Value {
+    let language = tag.split('-').next().unwrap();
+    [tag, language]
+        .iter()
+        .find_map(|t| cldr::read(&source.join("main"), t, file))
+        .unwrap_or_else(|| panic!("cldr-json has no {} for {}", file, tag))
+}
+
+/// Writes the numbers of a locale in the layout of cldr-json.
+fn write_locale(data: &Path, tag: &str, file: &str, numbers: Value) {
+    let mut identity = json!({ "language": tag.split('-').next().unwrap() });
+    if let Some((_, territory)) = tag.split_once('-') {
+        identity["territory"] = json!(territory);
+    }
+    let dir = data.join("main").join(tag);
+    fs::create_dir_all(&dir).unwrap();
+    write(
+        &dir.join(file),
+        &json!({ "main": { tag: { "identity": identity, "numbers": numbers } } }),
+    );
+}
+
+fn write(path: &Path, json: &Value) {
+    fs::write(path, serde_json::to_string_pretty(json).unwrap() + "\n").unwrap();
+}
@@ -0,0 +1,149 @@
+//! Generates the `define_iso!` table in `src/currency/iso_currencies.rs` from list one of ISO 4217, vendored in
+//! `data/iso4217/list-one.xml`.
+//!
+//! Codes, numeric codes and exponents come from the list. Locales, symbols, names and the other fields which ISO 4217
+//! does not define are kept from the current table, so new codes start with placeholders to review by hand.
+//!
+//! Run with `cargo run --example iso4217`.
+
+#[path = "../src/currency/iso4217.rs"]
+mod iso4217;
+
+use iso4217::ListCurrency;
+use std::fs;
+use std::path::Path;
+
+/// The fields of a `define_iso!` entry, in the order the macro expects them.
+const FIELDS: &[&str] = &[
+    "cash_exponent",
+    "disambiguated_symbol",
+    "exponent",
+    "iso_alpha_code",
+    "iso_numeric_code",
+    "kind",
+    "locale",
+    "minor_unit_name",
+    "minor_units",
+    "name",
+    "narrow_symbol",
+    "plural_name",
+    "symbol",
+    "symbol_first",
+];
+
+const START: &str = "    define_iso!(\n";
+const END: &str = "\n    );\n";
+
+/// An entry of the table, as the source of each field (e.g. `"AED"` or `2, // comment`).
+type Entry = (String, Vec<(String, String)>);
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let xml = fs::read_to_string(root.join("data/iso4217/list-one.xml")).unwrap();
+    let path = root.join("src/currency/iso_currencies.rs");
+    let source = fs::read_to_string(&path).unwrap();
+
+    let start = source
+        .find(START)
+        .expect("The table starts with define_iso!(")
+        + START.len();
+    let end = start + source[start..].find(END).expect("The table ends with );");
+    let current = parse_table(&source[start..end]);
+
+    let list = iso4217::currencies(&xml);
+    let entries: Vec<String> = list
+        .iter()
+        .map(|currency| {
+            let fields = current
+                .iter()
+                .find(|(code, _)| *code == currency.code)
+                .map(|(_, fields)| fields.clone())
+                .unwrap_or_else(|| {
+                    eprintln!(
+                        "Added {}, review its locale, names and symbols",
+                        currency.code
+                    );
+                    placeholder(currency)
+                });
+            entry(currency, fields)
+        })
+        .collect();
+    for (code, _) in current.iter() {
+        if !list.iter().any(|currency| currency.code == *code) {
+            eprintln!("Removed {}, consider adding it to historic", code);
+        }
+    }
+
+    let table = entries.join(",\n");
+    fs::write(
+        &path,
+        format!("{}{}{}", &source[..start], table, &source[end..]),
+    )
+    .unwrap();
+}
+
+/// Reads the entries of the current table.
+fn parse_table(table: &str) -> Vec<Entry> {
+    let mut entries: Vec<Entry> = Vec::new();
+    for line in table.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some(code) = line.strip_suffix(" : {") {
+            entries.push((code.to_string(), Vec::new()));
+        } else if let Some((field, value)) = line.split_once(": ") {
+            let (_, fields) = entries.last_mut().expect("Fields belong to an entry");
+            fields.push((field.to_string(), value.to_string()));
+        }
+    }
+    entries
+}
+
+/// Returns the fields of a code which is new to the table.
+fn placeholder(currency: &ListCurrency) -> Vec<(String, String)> {
+    let name = format!("{:?},", currency.name);
+    let symbol = format!("{:?},", currency.code);
+    let kind = if currency.is_fund {
+        Some("CurrencyKind::Fund,")
+    } else if currency.minor_units.is_none() {
+        Some("CurrencyKind::Special,")
+    } else {
+        None
+    };
+    let fields = [
+        ("kind", kind),
+        ("locale", Some("EnUs,")),
+        ("minor_units", Some("1,")),
+        ("name", Some(name.as_str())),
+        ("plural_name", Some(name.as_str())),
+        ("symbol", Some(symbol.as_str())),
+        ("symbol_first", Some("false,")),
+    ];
+    fields
+        .into_iter()
+        .filter_map(|(field, value)| Some((field.to_string(), value?.to_string())))
+        .collect()
+}
+
+/// Returns the source of an entry, with the codes and exponent of the list.
+fn entry(currency: &ListCurrency, mut fields: Vec<(String, String)>) -> String {
+    let mut set = |field: &str, value: String| {
+        match fields.iter_mut().find(|(f, _)| f == field) {
+            // Keeps the comment of an unchanged value.
+            Some((_, current)) if current.split(',').next() == value.split(',').next() => {}
+            Some((_, current)) => *current = value,
+            None => fields.push((field.to_string(), value)),
+        }
+    };
+    set(
+        "exponent",
+        format!("{},", currency.minor_units.unwrap_or(0)),
+    );
+    set("iso_alpha_code", format!("{:?},", currency.code));
+    set("iso_numeric_code", format!("{:?},", currency.numeric_code));
+
+    let mut out = format!("        {} : {{\n", currency.code);
+    for field in FIELDS {
+        if let Some((_, value)) = fields.iter().find(|(f, _)| f == field) {
+            out += &format!("            {}: {}\n", field, value);
+        }
+    }
+    out + "        }"
+}
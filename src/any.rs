@@ -0,0 +1,366 @@
+use crate::{FormattableCurrency, Locale, RegisteredCurrency, SymbolStyle};
+use rust_decimal::Decimal;
+use std::any::Any;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+#[cfg(feature = "historic")]
+use crate::historic;
+#[cfg(feature = "iso")]
+use crate::iso;
+
+/// A currency from any set, so amounts in iso, crypto and custom currencies can share a `Vec` or an `Exchange`.
+///
+/// Currencies are converted with `From`, or directly as variants (e.g. `&AnyCurrency::Iso(iso::USD)`). Two
+/// AnyCurrencies are equal when they hold the same currency of the same set.
+#[derive(Debug, Clone, Copy)]
+pub enum AnyCurrency {
+    #[cfg(feature = "iso")]
+    Iso(&'static iso::Currency),
+    #[cfg(feature = "historic")]
+    Historic(&'static historic::Currency),
+    Registered(&'static RegisteredCurrency),
+    /// A currency of a set created with `define_currency_set!`, such as `crypto`.
+    Custom(&'static dyn DynCurrency),
+}
+
+impl AnyCurrency {
+    fn inner(&self) -> &'static dyn DynCurrency {
+        match *self {
+            #[cfg(feature = "iso")]
+            AnyCurrency::Iso(currency) => currency,
+            #[cfg(feature = "historic")]
+            AnyCurrency::Historic(currency) => currency,
+            AnyCurrency::Registered(currency) => currency,
+            AnyCurrency::Custom(currency) => currency,
+        }
+    }
+}
+
+impl PartialEq for AnyCurrency {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner().dyn_eq(other.inner().as_any())
+    }
+}
+
+impl Eq for AnyCurrency {}
+
+impl Hash for AnyCurrency {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code().hash(state);
+    }
+}
+
+impl FormattableCurrency for AnyCurrency {
+    fn to_string(&self) -> String {
+        self.code().to_string()
+    }
+
+    fn exponent(&self) -> u32 {
+        self.inner().dyn_exponent()
+    }
+
+    fn code(&self) -> &'static str {
+        self.inner().dyn_code()
+    }
+
+    fn locale(&self) -> Locale {
+        self.inner().dyn_locale()
+    }
+
+    fn name(&self) -> &'static str {
+        self.inner().dyn_name()
+    }
+
+    fn plural_name(&self) -> &'static str {
+        self.inner().dyn_plural_name()
+    }
+
+    fn symbol(&self) -> &'static str {
+        self.inner().dyn_symbol()
+    }
+
+    fn narrow_symbol(&self) -> &'static str {
+        self.inner().dyn_narrow_symbol()
+    }
+
+    fn disambiguated_symbol(&self) -> &'static str {
+        self.inner().dyn_disambiguated_symbol()
+    }
+
+    fn symbol_first(&self) -> bool {
+        self.inner().dyn_symbol_first()
+    }
+
+    fn has_fixed_exponent(&self) -> bool {
+        self.inner().dyn_has_fixed_exponent()
+    }
+
+    fn cash_exponent(&self) -> u32 {
+        self.inner().dyn_cash_exponent()
+    }
+
+    fn minor_units(&self) -> u64 {
+        self.inner().dyn_minor_units()
+    }
+
+    fn numeric_code(&self) -> Option<&'static str> {
+        self.inner().dyn_numeric_code()
+    }
+
+    fn minor_unit_name(&self) -> Option<&'static str> {
+        self.inner().dyn_minor_unit_name()
+    }
+
+    fn display_name(&self, locale: Locale, count: Option<Decimal>) -> &'static str {
+        self.inner().dyn_display_name(locale, count)
+    }
+
+    fn styled_symbol(&self, style: SymbolStyle) -> &'static str {
+        self.inner().dyn_styled_symbol(style)
+    }
+}
+
+impl fmt::Display for AnyCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[cfg(feature = "iso")]
+impl From<&'static iso::Currency> for AnyCurrency {
+    fn from(currency: &'static iso::Currency) -> Self {
+        AnyCurrency::Iso(currency)
+    }
+}
+
+#[cfg(feature = "historic")]
+impl From<&'static historic::Currency> for AnyCurrency {
+    fn from(currency: &'static historic::Currency) -> Self {
+        AnyCurrency::Historic(currency)
+    }
+}
+
+impl From<&'static RegisteredCurrency> for AnyCurrency {
+    fn from(currency: &'static RegisteredCurrency) -> Self {
+        AnyCurrency::Registered(currency)
+    }
+}
+
+/// The methods of `FormattableCurrency` in a form which can be called through `&dyn DynCurrency`, for the
+/// custom sets of `AnyCurrency`. It is implemented for every currency.
+///
+/// The methods are prefixed so that they never shadow those of `FormattableCurrency`.
+pub trait DynCurrency: fmt::Debug + Sync {
+    fn as_any(&self) -> &dyn Any;
+    /// Whether `other` is the same type of currency, and equal to this one.
+    fn dyn_eq(&self, other: &dyn Any) -> bool;
+    fn dyn_exponent(&self) -> u32;
+    fn dyn_code(&self) -> &'static str;
+    fn dyn_locale(&self) -> Locale;
+    fn dyn_name(&self) -> &'static str;
+    fn dyn_plural_name(&self) -> &'static str;
+    fn dyn_symbol(&self) -> &'static str;
+    fn dyn_narrow_symbol(&self) -> &'static str;
+    fn dyn_disambiguated_symbol(&self) -> &'static str;
+    fn dyn_symbol_first(&self) -> bool;
+    fn dyn_has_fixed_exponent(&self) -> bool;
+    fn dyn_cash_exponent(&self) -> u32;
+    fn dyn_minor_units(&self) -> u64;
+    fn dyn_numeric_code(&self) -> Option<&'static str>;
+    fn dyn_minor_unit_name(&self) -> Option<&'static str>;
+    fn dyn_display_name(&self, locale: Locale, count: Option<Decimal>) -> &'static str;
+    fn dyn_styled_symbol(&self, style: SymbolStyle) -> &'static str;
+}
+
+impl<T: FormattableCurrency + fmt::Debug + Sync + 'static> DynCurrency for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn dyn_eq(&self, other: &dyn Any) -> bool {
+        other.downcast_ref::<T>() == Some(self)
+    }
+
+    fn dyn_exponent(&self) -> u32 {
+        self.exponent()
+    }
+
+    fn dyn_code(&self) -> &'static str {
+        self.code()
+    }
+
+    fn dyn_locale(&self) -> Locale {
+        self.locale()
+    }
+
+    fn dyn_name(&self) -> &'static str {
+        self.name()
+    }
+
+    fn dyn_plural_name(&self) -> &'static str {
+        self.plural_name()
+    }
+
+    fn dyn_symbol(&self) -> &'static str {
+        self.symbol()
+    }
+
+    fn dyn_narrow_symbol(&self) -> &'static str {
+        self.narrow_symbol()
+    }
+
+    fn dyn_disambiguated_symbol(&self) -> &'static str {
+        self.disambiguated_symbol()
+    }
+
+    fn dyn_symbol_first(&self) -> bool {
+        self.symbol_first()
+    }
+
+    fn dyn_has_fixed_exponent(&self) -> bool {
+        self.has_fixed_exponent()
+    }
+
+    fn dyn_cash_exponent(&self) -> u32 {
+        self.cash_exponent()
+    }
+
+    fn dyn_minor_units(&self) -> u64 {
+        self.minor_units()
+    }
+
+    fn dyn_numeric_code(&self) -> Option<&'static str> {
+        self.numeric_code()
+    }
+
+    fn dyn_minor_unit_name(&self) -> Option<&'static str> {
+        self.minor_unit_name()
+    }
+
+    fn dyn_display_name(&self, locale: Locale, count: Option<Decimal>) -> &'static str {
+        self.display_name(locale, count)
+    }
+
+    fn dyn_styled_symbol(&self, style: SymbolStyle) -> &'static str {
+        self.styled_symbol(style)
+    }
+}
+
+#[cfg(all(test, feature = "iso"))]
+mod tests {
+    use super::*;
+    use crate::{define_currency_set, Exchange, ExchangeRate, Money, MoneyError};
+    use rust_decimal_macros::*;
+
+    define_currency_set!(
+        game {
+            GIL: {
+                code: "GIL",
+                exponent: 0,
+                locale: EnUs,
+                minor_units: 1,
+                name: "Gil",
+                symbol: "G",
+                symbol_first: false,
+            }
+        }
+    );
+
+    define_currency_set!(
+        other {
+            GIL: {
+                code: "GIL",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 100,
+                name: "Gil",
+                symbol: "G",
+                symbol_first: false,
+            }
+        }
+    );
+
+    #[test]
+    fn any_currency_holds_currencies_of_every_set() {
+        let gil = AnyCurrency::from(game::GIL);
+        assert_eq!(gil, AnyCurrency::Custom(game::GIL));
+        assert_ne!(gil, AnyCurrency::from(other::GIL));
+        assert_eq!(gil.code(), "GIL");
+        assert_eq!(format!("{}", gil), "GIL");
+
+        let amounts = [
+            Money::from_major(5, &AnyCurrency::Iso(crate::iso::USD)),
+            Money::from_major(1_000, &gil),
+        ];
+        assert_eq!(amounts[0].to_string(), "$5");
+        assert_eq!(amounts[1].to_string(), "1,000G");
+        assert_ne!(amounts[0].currency(), amounts[1].currency());
+    }
+
+    #[test]
+    fn exchange_converts_between_sets() {
+        let usd = AnyCurrency::from(crate::iso::USD);
+        let gil = AnyCurrency::from(game::GIL);
+
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(&usd, &gil, dec!(250)).unwrap());
+        assert_eq!(
+            exchange.convert(&Money::from_major(4, &usd), &gil),
+            Ok(Money::from_major(1_000, &gil))
+        );
+        assert_eq!(
+            exchange.convert(&Money::from_major(1_000, &gil), &usd),
+            Err(MoneyError::InvalidCurrency)
+        );
+    }
+
+    #[test]
+    fn exchange_keeps_rates_of_currencies_with_the_same_code() {
+        let usd = AnyCurrency::from(crate::iso::USD);
+        let game_gil = AnyCurrency::from(game::GIL);
+        let other_gil = AnyCurrency::from(other::GIL);
+
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(&usd, &game_gil, dec!(250)).unwrap());
+        exchange.set_rate(&ExchangeRate::new(&usd, &other_gil, dec!(2)).unwrap());
+        assert_eq!(
+            exchange.convert(&Money::from_major(4, &usd), &game_gil),
+            Ok(Money::from_major(1_000, &game_gil))
+        );
+        assert_eq!(
+            exchange.convert(&Money::from_major(4, &usd), &other_gil),
+            Ok(Money::from_major(8, &other_gil))
+        );
+
+        exchange.set_rate(&ExchangeRate::new(&usd, &other_gil, dec!(3)).unwrap());
+        assert_eq!(exchange.get_rate(&usd, &other_gil).unwrap().to, &other_gil);
+        assert_eq!(
+            exchange.convert(&Money::from_major(4, &usd), &other_gil),
+            Ok(Money::from_major(12, &other_gil))
+        );
+        assert_eq!(
+            exchange.convert(&Money::from_major(4, &usd), &game_gil),
+            Ok(Money::from_major(1_000, &game_gil))
+        );
+        assert_eq!(
+            exchange.convert(&Money::from_major(1, &game_gil), &other_gil),
+            Err(MoneyError::InvalidCurrency)
+        );
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn exchange_converts_fiat_to_crypto() {
+        let usd = AnyCurrency::from(crate::iso::USD);
+        let btc = AnyCurrency::from(crate::crypto::BTC);
+
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(&usd, &btc, dec!(0.00002)).unwrap());
+        let bitcoin = exchange
+            .convert(&Money::from_major(1_000, &usd), &btc)
+            .unwrap();
+        assert_eq!(bitcoin, Money::from_str("0.02", &btc).unwrap());
+        assert_eq!(bitcoin.currency().minor_unit_name(), Some("Satoshi"));
+    }
+}
@@ -0,0 +1,201 @@
+//! Locales and currency display data generated from a snapshot of the Unicode CLDR.
+//!
+//! The snapshot is vendored in `data/cldr` using the layout of cldr-json, and is compiled in by the
+//! build script when the `cldr` feature is enabled.
+use crate::{
+    Direction, LocalFormat, Locale, NegativeStyle, NumberingSystem, PluralCategory, PluralRule,
+    Spacing,
+};
+use std::borrow::Cow;
+
+include!(concat!(env!("OUT_DIR"), "/cldr.rs"));
+
+impl CldrLocale {
+    /// Returns the LocalFormat of the locale.
+    pub fn local_format(&self) -> LocalFormat {
+        self.data().0
+    }
+
+    /// Returns the CLDR standard currency pattern of the locale (e.g. `¤ #,##0.00`).
+    pub fn currency_pattern(&self) -> &'static str {
+        self.data().1
+    }
+}
+
+/// Stores how a currency is displayed in a specific locale.
+#[derive(Debug, PartialEq, Eq)]
+pub struct CldrCurrency {
+    /// The symbol of the currency in the locale (e.g. US$ for USD in en-CA)
+    pub symbol: &'static str,
+    /// The shortest symbol of the currency, which may be ambiguous (e.g. $)
+    pub narrow_symbol: &'static str,
+    /// The name of the currency on its own (e.g. US Dollar)
+    pub display_name: &'static str,
+    /// The name of the currency after an amount, for each plural category of the locale (e.g. US dollars)
+    pub plural_names: &'static [(PluralCategory, &'static str)],
+}
+
+impl CldrCurrency {
+    /// Returns the name of the currency for an amount in the given plural category.
+    pub fn plural_name(&self, category: PluralCategory) -> &'static str {
+        let name = |category| {
+            self.plural_names
+                .iter()
+                .find(|(c, _)| *c == category)
+                .map(|(_, name)| *name)
+        };
+        name(category)
+            .or_else(|| name(PluralCategory::Other))
+            .unwrap_or(self.display_name)
+    }
+}
+
+/// Returns the CLDR data of a currency in a locale, falling back to the locale's language.
+///
+/// For example, USD in `Locale::EnCa` has its own symbol (US$), while `Locale::EnUs` uses the data of `en`.
+pub fn currency(locale: Locale, code: &str) -> Option<&'static CldrCurrency> {
+    let tag = LocalFormat::from_locale(locale).name.to_ascii_lowercase();
+    let language = tag.split('-').next().unwrap_or(&tag);
+    find(&tag, code).or_else(|| find(language, code))
+}
+
+fn find(tag: &str, code: &str) -> Option<&'static CldrCurrency> {
+    CURRENCIES
+        .binary_search_by(|(t, c, _)| (*t, *c).cmp(&(tag, code)))
+        .ok()
+        .map(|index| &CURRENCIES[index].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_local_formats() {
+        let format = LocalFormat::from_locale(Locale::Cldr(CldrLocale::DeAt));
+        assert_eq!(format.name, "de-AT");
+        assert_eq!(format.digit_separator, '\u{a0}');
+        assert_eq!(format.exponent_separator, ',');
+        assert!(format.symbol_first);
+        assert_eq!(format.symbol_spacing, Spacing::NoBreak);
+        assert_eq!(CldrLocale::DeAt.currency_pattern(), "¤\u{a0}#,##0.00");
+
+        // Currency specific separators take precedence.
+        let format = CldrLocale::FrCh.local_format();
+        assert_eq!(format.exponent_separator, '.');
+        assert!(!format.symbol_first);
+
+        let format = CldrLocale::FaIr.local_format();
+        assert_eq!(
+            format.numbering_system,
+            NumberingSystem::ExtendedArabicIndic
+        );
+        assert_eq!(format.plural_rule, PluralRule::Hindi);
+        assert_eq!(format.direction, Direction::RightToLeft);
+        assert!(format.symbol_first);
+
+        assert_eq!(
+            CldrLocale::PtPt.local_format().plural_rule,
+            PluralRule::Italian
+        );
+    }
+
+    #[test]
+    fn built_in_locales_match_the_snapshot() {
+        for format in BUILT_IN {
+            let locale: Locale = format.name.parse().unwrap();
+            assert_eq!(LocalFormat::from_locale(locale), *format);
+        }
+        // en-EU and en-BY are not CLDR locales.
+        assert_eq!(BUILT_IN.len(), Locale::BUILT_IN.len() - 2);
+    }
+
+    #[test]
+    fn generated_locales_format_money() {
+        use crate::{define_currency_set, Money};
+
+        define_currency_set!(
+            test {
+                EUR: {
+                    code: "EUR",
+                    exponent: 2,
+                    locale: Cldr(crate::cldr::CldrLocale::DeAt),
+                    minor_units: 1,
+                    name: "Euro",
+                    symbol: "€",
+                    symbol_first: true,
+                }
+            }
+        );
+        let eur = test::find("EUR").unwrap();
+        let money = Money::from_minor(-123_456, eur);
+        assert_eq!(format!("{}", money), "-€\u{a0}1\u{a0}234,56");
+    }
+
+    #[test]
+    fn finds_currencies_with_language_fallback() {
+        let usd = currency(Locale::EnCa, "USD").unwrap();
+        assert_eq!(usd.symbol, "US$");
+        assert_eq!(usd.narrow_symbol, "$");
+
+        let usd = currency(Locale::EnUs, "USD").unwrap();
+        assert_eq!(usd.symbol, "$");
+        assert_eq!(usd.plural_name(PluralCategory::One), "US dollar");
+        assert_eq!(usd.plural_name(PluralCategory::Other), "US dollars");
+
+        let rub = currency(Locale::RuRu, "RUB").unwrap();
+        assert_eq!(rub.plural_name(PluralCategory::Few), "российских рубля");
+        // Categories a locale does not use fall back to other.
+        assert_eq!(rub.plural_name(PluralCategory::Two), "российского рубля");
+
+        let jpy = currency(Locale::Cldr(CldrLocale::EnGb), "JPY").unwrap();
+        assert_eq!(jpy.display_name, "Japanese Yen");
+
+        assert_eq!(currency(Locale::EnUs, "XYZ"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "iso")]
+    fn localizes_display_names() {
+        use crate::{iso, FormattableCurrency};
+        use rust_decimal::Decimal;
+
+        let usd = iso::USD;
+        assert_eq!(usd.display_name(Locale::DeDe, None), "US-Dollar");
+        assert_eq!(
+            usd.display_name(Locale::FrFr, Some(Decimal::ONE)),
+            "dollar des États-Unis"
+        );
+        assert_eq!(
+            usd.display_name(Locale::FrFr, Some(Decimal::from(5))),
+            "dollars des États-Unis"
+        );
+        assert_eq!(
+            usd.display_name(Locale::RuRu, Some(Decimal::from(3))),
+            "доллара США"
+        );
+        assert_eq!(
+            usd.display_name(Locale::RuRu, Some(Decimal::from(5))),
+            "долларов США"
+        );
+        assert_eq!(
+            usd.display_name(Locale::ArAe, Some(Decimal::from(2))),
+            "دولاران أمريكيان"
+        );
+        assert_eq!(
+            usd.display_name(Locale::EnUs, Some(Decimal::from(2))),
+            "US dollars"
+        );
+
+        // Currencies without data fall back to English.
+        let aed = iso::AED;
+        assert_eq!(
+            aed.display_name(Locale::DeDe, None),
+            "United Arab Emirates Dirham"
+        );
+        assert_eq!(
+            aed.display_name(Locale::DeDe, Some(Decimal::from(2))),
+            "United Arab Emirates Dirhams"
+        );
+    }
+}
@@ -1,10 +1,21 @@
-use crate::Locale;
+use crate::{Locale, PluralCategory, PluralRule};
+use rust_decimal::Decimal;
+use std::cmp::Ordering;
 
 #[cfg(feature = "crypto")]
 mod crypto_currencies;
 #[cfg(feature = "crypto")]
 pub use crypto_currencies::crypto;
 
+#[cfg(feature = "historic")]
+mod historic_currencies;
+#[cfg(feature = "historic")]
+pub use historic_currencies::historic;
+
+#[cfg(all(test, feature = "iso"))]
+#[allow(dead_code)]
+mod iso4217;
+
 #[cfg(feature = "iso")]
 mod iso_currencies;
 #[cfg(feature = "iso")]
@@ -20,27 +31,152 @@ pub trait FormattableCurrency: PartialEq + Eq + Copy {
 
     fn locale(&self) -> Locale;
 
+    /// The singular name of the currency (e.g. US Dollar). Defaults to the code.
+    fn name(&self) -> &'static str {
+        self.code()
+    }
+
+    /// The plural name of the currency (e.g. US Dollars). Defaults to the singular name.
+    fn plural_name(&self) -> &'static str {
+        self.name()
+    }
+
     fn symbol(&self) -> &'static str;
 
+    /// The shortest symbol of the currency, which may be shared with other currencies (e.g. $ for USD). Defaults
+    /// to the symbol.
+    fn narrow_symbol(&self) -> &'static str {
+        self.symbol()
+    }
+
+    /// A symbol which tells the currency apart from others with the same symbol (e.g. US$ for USD). Defaults to
+    /// the symbol.
+    fn disambiguated_symbol(&self) -> &'static str {
+        self.symbol()
+    }
+
     fn symbol_first(&self) -> bool;
+
+    /// Whether amounts are rounded to `exponent` digits when formatted. Currencies without a minor unit, like the
+    /// precious metals of ISO 4217, are formatted with all the digits of their amount.
+    fn has_fixed_exponent(&self) -> bool {
+        true
+    }
+
+    /// The decimal places of cash payments, which can be fewer than the `exponent` once the smallest coins have
+    /// been withdrawn (e.g. 0 for SEK, whose exponent is 2).
+    fn cash_exponent(&self) -> u32 {
+        self.exponent()
+    }
+
+    /// The `minor_units` the currency was defined with. ISO currencies hold their smallest denomination in minor
+    /// units (e.g. 5 for CHF), other sets usually the number of minor units in a major unit (e.g. 100_000_000 for
+    /// BTC). Defaults to 10 to the power of the exponent.
+    fn minor_units(&self) -> u64 {
+        10u64.saturating_pow(self.exponent())
+    }
+
+    /// The ISO numeric code of the currency (e.g. 840 for USD), if it has one.
+    fn numeric_code(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// The singular name of the minor unit (e.g. Cent for USD), if it has one.
+    fn minor_unit_name(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Returns the name of the currency in a locale, on its own or for an amount of `count`.
+    ///
+    /// Names come from the CLDR table of the `cldr` feature, in the plural form the locale's rules pick for
+    /// `count`. Without the feature, or for currencies missing from the table, the English `name` and
+    /// `plural_name` are used.
+    fn display_name(&self, locale: Locale, count: Option<Decimal>) -> &'static str {
+        #[cfg(feature = "cldr")]
+        if let Some(currency) = crate::cldr::currency(locale, self.code()) {
+            return match count {
+                Some(count) => {
+                    let rule = crate::LocalFormat::from_locale(locale).plural_rule;
+                    currency.plural_name(rule.category(&count))
+                }
+                None => currency.display_name,
+            };
+        }
+        #[cfg(not(feature = "cldr"))]
+        let _ = locale;
+
+        match count.map(|count| PluralRule::English.category(&count)) {
+            None | Some(PluralCategory::One) => self.name(),
+            Some(_) => self.plural_name(),
+        }
+    }
+
+    /// Returns the symbol of the currency in the given style.
+    fn styled_symbol(&self, style: SymbolStyle) -> &'static str {
+        match style {
+            SymbolStyle::Standard => self.symbol(),
+            SymbolStyle::Narrow => self.narrow_symbol(),
+            SymbolStyle::Disambiguated => self.disambiguated_symbol(),
+            SymbolStyle::Local(locale) => {
+                #[cfg(feature = "cldr")]
+                if let Some(currency) = crate::cldr::currency(locale, self.code()) {
+                    return currency.symbol;
+                }
+                if locale == self.locale() {
+                    self.narrow_symbol()
+                } else {
+                    self.disambiguated_symbol()
+                }
+            }
+        }
+    }
+}
+
+/// Selects which of a currency's symbols is displayed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum SymbolStyle {
+    /// The currency's symbol (e.g. $ for USD)
+    Standard,
+    /// The shortest symbol, which may be ambiguous (e.g. $ for USD and CAD)
+    Narrow,
+    /// A symbol which is unique among currencies (e.g. US$ for USD, CA$ for CAD)
+    Disambiguated,
+    /// The symbol readers in a locale expect. Uses the CLDR symbol of the currency in the locale when the `cldr`
+    /// feature is enabled.
+    ///
+    /// Without CLDR data this is only a heuristic: the narrow symbol in the currency's own locale and the
+    /// disambiguated one elsewhere (e.g. $ for USD in en-US but US$ in en-CA). It knows nothing of the locale's
+    /// own currency, so output can be ambiguous, like $ for CAD in en-CA contexts which also show USD.
+    Local(Locale),
 }
 
 #[macro_export]
 /// Create custom currencies for use with Money types
+///
+/// A set can declare extra fields after its name (e.g. `game(tradable: bool) { ... }`), which each of its
+/// currencies sets after `symbol_first`. Their types must implement `Debug`, `Eq`, `Copy` and `Hash`, and are
+/// resolved inside the set's module, so types of the enclosing module are named through `super::`.
 macro_rules! define_currency_set {
     (
         $(
             $(#[$attr:meta])*
-            $module:ident {
+            $module:ident $(($($(#[$field_attr:meta])* $field:ident: $type:ty),* $(,)?))? {
                 $(
                     $currency:ident: {
+                    $(cash_exponent: $cash:expr,)?
                     code: $code:expr,
+                    $(disambiguated_symbol: $dis:expr,)?
                     exponent: $exp:expr,
                     locale: $loc:expr,
+                    $(minor_unit_name: $minor_name:expr,)?
                     minor_units: $min_dem:expr,
                     name: $name:expr,
+                    $(narrow_symbol: $narrow:expr,)?
+                    $(numeric_code: $num:expr,)?
+                    $(plural_name: $plural:expr,)?
                     symbol: $sym:expr,
                     symbol_first: $sym_first:expr,
+                    $($extra:ident: $value:expr,)*
                     }
                 ),+
             }
@@ -55,12 +191,19 @@ macro_rules! define_currency_set {
                     #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
                     pub struct Currency {
                         pub code: &'static str,
+                        pub numeric_code: Option<&'static str>,
                         pub exponent: u32,
+                        pub cash_exponent: u32,
                         pub locale: Locale,
                         pub minor_units: u64,
+                        pub minor_unit_name: Option<&'static str>,
                         pub name: &'static str,
+                        pub plural_name: &'static str,
                         pub symbol: &'static str,
+                        pub narrow_symbol: &'static str,
+                        pub disambiguated_symbol: &'static str,
                         pub symbol_first: bool,
+                        $($($(#[$field_attr])* pub $field: $type,)*)?
                     }
 
                     impl FormattableCurrency for Currency {
@@ -72,6 +215,10 @@ macro_rules! define_currency_set {
                             self.exponent
                         }
 
+                        fn cash_exponent(&self) -> u32 {
+                            self.cash_exponent
+                        }
+
                         fn code(&self) -> &'static str {
                             self.code
                         }
@@ -80,32 +227,135 @@ macro_rules! define_currency_set {
                             self.locale
                         }
 
+                        fn minor_units(&self) -> u64 {
+                            self.minor_units
+                        }
+
+                        fn numeric_code(&self) -> Option<&'static str> {
+                            self.numeric_code
+                        }
+
+                        fn minor_unit_name(&self) -> Option<&'static str> {
+                            self.minor_unit_name
+                        }
+
+                        fn name(&self) -> &'static str {
+                            self.name
+                        }
+
+                        fn plural_name(&self) -> &'static str {
+                            self.plural_name
+                        }
+
                         fn symbol(&self) -> &'static str {
                             self.symbol
                         }
 
+                        fn narrow_symbol(&self) -> &'static str {
+                            self.narrow_symbol
+                        }
+
+                        fn disambiguated_symbol(&self) -> &'static str {
+                            self.disambiguated_symbol
+                        }
+
                         fn symbol_first(&self) -> bool {
                             self.symbol_first
                         }
                     }
 
+                    impl From<&'static Currency> for $crate::AnyCurrency {
+                        fn from(currency: &'static Currency) -> Self {
+                            $crate::AnyCurrency::Custom(currency)
+                        }
+                    }
+
                     $(
                         pub const $currency: &'static self::Currency = &self::Currency {
                         code: $code,
+                        numeric_code: $crate::__or_default!(None $(, Some($num))?),
                         exponent: $exp,
+                        cash_exponent: $crate::__or_default!($exp $(, $cash)?),
                         locale: $loc,
                         minor_units: $min_dem,
+                        minor_unit_name: $crate::__or_default!(None $(, Some($minor_name))?),
                         name: $name,
+                        plural_name: $crate::__or_default!($name $(, $plural)?),
                         symbol: $sym,
+                        narrow_symbol: $crate::__or_default!($sym $(, $narrow)?),
+                        disambiguated_symbol: $crate::__or_default!($sym $(, $dis)?),
                         symbol_first: $sym_first,
+                        $($extra: $value,)*
                         };
                     )+
 
+                    /// Returns the currency with a code, ignoring case if no code matches exactly.
+                    #[allow(dead_code)]
                     pub fn find(code: &str) -> Option<&'static self::Currency> {
                         match code {
                             $($code => (Some($currency)),)+
-                            _ => None,
+                            _ => $crate::__find_ignore_case(CURRENCIES, code, |currency| currency.code),
+                        }
+                    }
+
+                    #[allow(dead_code)]
+                    pub fn find_by_num_code(code: &str) -> Option<&'static self::Currency> {
+                        $crate::__find_all(BY_NUMERIC_CODE, code, |currency| currency.numeric_code.unwrap_or(""))
+                            .first()
+                            .copied()
+                    }
+
+                    /// Returns every currency with a symbol, in alphabetical order of their codes.
+                    #[allow(dead_code)]
+                    pub fn find_by_symbol(symbol: &str) -> &'static [&'static self::Currency] {
+                        $crate::__find_all(BY_SYMBOL, symbol, |currency| currency.symbol)
+                    }
+
+                    /// Returns the currencies whose code, name or plural name resembles `query`, best matches first.
+                    #[allow(dead_code)]
+                    pub fn search(query: &str) -> Vec<&'static self::Currency> {
+                        $crate::__search(CURRENCIES, query)
+                    }
+
+                    /// Every currency of the set, in alphabetical order of their codes.
+                    #[allow(dead_code)]
+                    pub const CURRENCIES: &[&self::Currency] =
+                        &$crate::__sorted_by!([$($currency),+], currency => currency.code);
+
+                    const BY_SYMBOL: &[&self::Currency] =
+                        &$crate::__sorted_by!(
+                        [$($currency),+],
+                        currency => currency.code,
+                        currency => currency.symbol
+                    );
+
+                    const BY_NUMERIC_CODE: &[&self::Currency] = &$crate::__sorted_by!(
+                        [$($currency),+],
+                        currency => match currency.numeric_code {
+                            Some(code) => code,
+                            None => "",
                         }
+                    );
+
+                    /// Iterates over the currencies of the set, in alphabetical order of their codes.
+                    #[allow(dead_code)]
+                    pub fn iter() -> impl Iterator<Item = &'static self::Currency> {
+                        CURRENCIES.iter().copied()
+                    }
+
+                    #[allow(dead_code)]
+                    pub fn with_exponent(exponent: u32) -> impl Iterator<Item = &'static self::Currency> {
+                        iter().filter(move |currency| currency.exponent == exponent)
+                    }
+
+                    #[allow(dead_code)]
+                    pub fn with_locale(locale: Locale) -> impl Iterator<Item = &'static self::Currency> {
+                        iter().filter(move |currency| currency.locale == locale)
+                    }
+
+                    #[allow(dead_code)]
+                    pub fn with_symbol_first(symbol_first: bool) -> impl Iterator<Item = &'static self::Currency> {
+                        iter().filter(move |currency| currency.symbol_first == symbol_first)
                     }
 
                     impl fmt::Display for Currency {
@@ -118,16 +368,197 @@ macro_rules! define_currency_set {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+/// Expands to the optional value of a `define_currency_set!` field, or to its default when omitted.
+macro_rules! __or_default {
+    ($default:expr) => {
+        $default
+    };
+    ($default:expr, $value:expr) => {
+        $value
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+/// Expands to an array of currencies sorted by string keys with `__code_lt`, in a const context.
+///
+/// Each key sorts the array stably in turn, so the last key is the primary one and earlier keys break its ties.
+macro_rules! __sorted_by {
+    ([$($currencies:expr),+] $(, $currency:ident => $key:expr)+) => {{
+        let mut currencies = [$($currencies),+];
+        $(
+            let mut i = 1;
+            while i < currencies.len() {
+                let mut j = i;
+                while j > 0 && {
+                    let $currency = currencies[j];
+                    let key = $key;
+                    let $currency = currencies[j - 1];
+                    $crate::__code_lt(key, $key)
+                } {
+                    let previous = currencies[j - 1];
+                    currencies[j - 1] = currencies[j];
+                    currencies[j] = previous;
+                    j -= 1;
+                }
+                i += 1;
+            }
+        )+
+        currencies
+    }};
+}
+
+#[doc(hidden)]
+/// Returns whether code `a` sorts before `b`, comparing ASCII letters without case and then bytes.
+pub const fn __code_lt(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        let (x, y) = (a[i].to_ascii_uppercase(), b[i].to_ascii_uppercase());
+        if x != y {
+            return x < y;
+        }
+        i += 1;
+    }
+    if a.len() != b.len() {
+        return a.len() < b.len();
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    false
+}
+
+pub(crate) fn cmp_ignore_case(a: &str, b: &str) -> Ordering {
+    a.bytes()
+        .map(|c| c.to_ascii_uppercase())
+        .cmp(b.bytes().map(|c| c.to_ascii_uppercase()))
+}
+
+#[doc(hidden)]
+/// Finds `code` in currencies sorted by code with `__code_lt`, preferring a match in the same case.
+pub fn __find_ignore_case<T>(
+    currencies: &'static [&'static T],
+    code: &str,
+    key: fn(&T) -> &'static str,
+) -> Option<&'static T> {
+    let start =
+        currencies.partition_point(|&currency| cmp_ignore_case(key(currency), code).is_lt());
+    let mut matches = currencies[start..]
+        .iter()
+        .copied()
+        .take_while(|&currency| key(currency).eq_ignore_ascii_case(code));
+    let first = matches.next()?;
+    Some(
+        std::iter::once(first)
+            .chain(matches)
+            .find(|&currency| key(currency) == code)
+            .unwrap_or(first),
+    )
+}
+
+#[doc(hidden)]
+/// Returns the currencies whose key is `value`, in currencies sorted by that key with `__code_lt`. Currencies with an
+/// empty key are never returned.
+pub fn __find_all<T>(
+    currencies: &'static [&'static T],
+    value: &str,
+    key: fn(&T) -> &'static str,
+) -> &'static [&'static T] {
+    if value.is_empty() {
+        return &[];
+    }
+    let start = currencies.partition_point(|&currency| __code_lt(key(currency), value));
+    let end = currencies.partition_point(|&currency| !__code_lt(value, key(currency)));
+    &currencies[start..end.max(start)]
+}
+
+#[doc(hidden)]
+/// Ranks the currencies whose code, name or plural name resembles `query`, keeping the order of `currencies`
+/// among equally good matches.
+pub fn __search<T: FormattableCurrency>(
+    currencies: &'static [&'static T],
+    query: &str,
+) -> Vec<&'static T> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches: Vec<(usize, &'static T)> = currencies
+        .iter()
+        .filter_map(|&currency| {
+            let rank = if currency.code().eq_ignore_ascii_case(&query) {
+                Some(0)
+            } else {
+                [currency.name(), currency.plural_name()]
+                    .iter()
+                    .filter_map(|name| match_rank(&query, &name.to_lowercase()))
+                    .min()
+            };
+            rank.map(|rank| (rank, currency))
+        })
+        .collect();
+    matches.sort_by_key(|(rank, _)| *rank);
+    matches.into_iter().map(|(_, currency)| currency).collect()
+}
+
+/// Ranks how well a lowercase name matches a lowercase query, from exact (0) to a near miss (5).
+fn match_rank(query: &str, name: &str) -> Option<usize> {
+    let mut words = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty());
+    if name == query {
+        Some(1)
+    } else if name.starts_with(query) {
+        Some(2)
+    } else if words.clone().any(|word| word.starts_with(query)) {
+        Some(3)
+    } else if name.contains(query) {
+        Some(4)
+    } else if edit_distance(query, name) <= query.chars().count() / 4
+        || query.chars().count() >= 4 && words.any(|word| edit_distance(query, word) <= 1)
+    {
+        Some(5)
+    } else {
+        None
+    }
+}
+
+/// Returns the Levenshtein distance between two strings, in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, b) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a != *b);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     define_currency_set!(
       real {
         USD: {
           code: "USD",
+          disambiguated_symbol: "US$",
           exponent: 2,
           locale: EnUs,
           minor_units: 100,
           name: "USD",
+          narrow_symbol: "$",
+          plural_name: "USDs",
           symbol: "$",
           symbol_first: true,
         }
@@ -137,14 +568,86 @@ mod tests {
             code: "FOO",
             exponent: 3,
             locale: EnUs,
+            minor_unit_name: "Fooling",
             minor_units: 100,
             name: "FOO",
+            numeric_code: "901",
             symbol: "F",
             symbol_first: true,
+          },
+        ZED: {
+            code: "ZED",
+            exponent: 0,
+            locale: DeDe,
+            minor_units: 1,
+            name: "ZED",
+            symbol: "Z",
+            symbol_first: false,
+          },
+        ABC: {
+            code: "abc",
+            exponent: 3,
+            locale: EnUs,
+            minor_units: 1_000,
+            name: "Alphabet Coin",
+            numeric_code: "900",
+            symbol: "F",
+            symbol_first: false,
+          }
+      }
+    );
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum Issuer {
+        Crown,
+        Guild,
+    }
+
+    define_currency_set!(
+      game(
+        /// Whether players can trade the currency.
+        tradable: bool,
+        issuer: super::Issuer,
+        display_order: u32,
+      ) {
+        GEM: {
+            code: "GEM",
+            exponent: 0,
+            locale: EnUs,
+            minor_units: 1,
+            name: "Gem",
+            symbol: "G",
+            symbol_first: false,
+            tradable: false,
+            issuer: super::Issuer::Crown,
+            display_order: 2,
+          },
+        GOLD: {
+            code: "GOLD",
+            exponent: 2,
+            locale: EnUs,
+            minor_units: 100,
+            name: "Gold",
+            symbol: "g",
+            symbol_first: false,
+            tradable: true,
+            issuer: super::Issuer::Guild,
+            display_order: 1,
           }
       }
     );
 
+    #[test]
+    fn sets_carry_custom_fields() {
+        assert!(game::GOLD.tradable && !game::GEM.tradable);
+        assert_eq!(game::find("gem").unwrap().issuer, Issuer::Crown);
+
+        let mut by_order = game::iter().collect::<Vec<_>>();
+        by_order.sort_by_key(|currency| currency.display_order);
+        assert_eq!(by_order, [game::GOLD, game::GEM]);
+        assert_eq!(crate::Money::from_major(3, game::GEM).to_string(), "3G");
+    }
+
     #[test]
     fn currencies_in_different_modules_are_not_equal() {
         assert_eq!(real::USD.code, "USD");
@@ -156,4 +659,175 @@ mod tests {
         assert_eq!(real::find("USD").unwrap().code, "USD");
         assert_eq!(magic::find("FOO").unwrap().code, "FOO");
     }
+
+    #[test]
+    fn sets_find_currencies_without_case_and_by_numeric_code() {
+        assert_eq!(magic::find("foo"), Some(magic::FOO));
+        assert_eq!(magic::find("ABC"), Some(magic::ABC));
+        assert_eq!(magic::find_by_num_code("900"), Some(magic::ABC));
+        assert_eq!(magic::find_by_num_code("901"), Some(magic::FOO));
+        assert_eq!(magic::find_by_num_code(""), None);
+        assert_eq!(magic::ZED.numeric_code, None);
+        assert_eq!(real::find_by_num_code("840"), None);
+    }
+
+    #[test]
+    fn sets_find_currencies_by_symbol_and_name() {
+        assert_eq!(magic::find_by_symbol("F"), [magic::ABC, magic::FOO]);
+        assert_eq!(magic::find_by_symbol("Z"), [magic::ZED]);
+        assert!(magic::find_by_symbol("$").is_empty());
+        assert_eq!(magic::search("alphabet"), [magic::ABC]);
+        assert_eq!(magic::search("Alfabet Coin"), [magic::ABC]);
+        assert_eq!(magic::search("zed"), [magic::ZED]);
+    }
+
+    #[test]
+    fn search_ranks_matches() {
+        assert_eq!(super::match_rank("euro", "euro"), Some(1));
+        assert_eq!(super::match_rank("us", "us dollar"), Some(2));
+        assert_eq!(super::match_rank("dollar", "us dollar"), Some(3));
+        assert_eq!(super::match_rank("oll", "us dollar"), Some(4));
+        assert_eq!(super::match_rank("dolar", "us dollar"), Some(5));
+        assert_eq!(super::match_rank("yen", "euro"), None);
+        assert_eq!(super::edit_distance("kitten", "sitting"), 3);
+    }
+
+    fn metadata<T: super::FormattableCurrency>(
+        currency: T,
+    ) -> (
+        &'static str,
+        u64,
+        Option<&'static str>,
+        Option<&'static str>,
+    ) {
+        (
+            currency.name(),
+            currency.minor_units(),
+            currency.numeric_code(),
+            currency.minor_unit_name(),
+        )
+    }
+
+    #[test]
+    fn currencies_expose_metadata() {
+        assert_eq!(
+            metadata(*magic::FOO),
+            ("FOO", 100, Some("901"), Some("Fooling"))
+        );
+        assert_eq!(metadata(*magic::ZED), ("ZED", 1, None, None));
+    }
+
+    /// A currency implemented by hand, with only the required methods.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct Token;
+
+    impl super::FormattableCurrency for Token {
+        fn to_string(&self) -> String {
+            "TOK".to_string()
+        }
+
+        fn exponent(&self) -> u32 {
+            0
+        }
+
+        fn code(&self) -> &'static str {
+            "TOK"
+        }
+
+        fn locale(&self) -> crate::Locale {
+            crate::Locale::EnUs
+        }
+
+        fn symbol(&self) -> &'static str {
+            "T"
+        }
+
+        fn symbol_first(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn trait_methods_have_defaults() {
+        use super::FormattableCurrency;
+
+        assert_eq!(Token.name(), "TOK");
+        assert_eq!(Token.plural_name(), "TOK");
+        assert_eq!(Token.minor_units(), 1);
+        assert_eq!(Token.narrow_symbol(), "T");
+        assert_eq!(Token.disambiguated_symbol(), "T");
+        assert_eq!(
+            Token.styled_symbol(super::SymbolStyle::Local(crate::Locale::EnCa)),
+            "T"
+        );
+        assert_eq!(crate::Money::from_major(3, &Token).to_string(), "3T");
+    }
+
+    #[test]
+    fn plural_name_defaults_to_name() {
+        assert_eq!(real::USD.plural_name, "USDs");
+        assert_eq!(magic::FOO.plural_name, "FOO");
+    }
+
+    #[test]
+    #[cfg(not(feature = "cldr"))]
+    fn display_name_falls_back_to_english() {
+        use crate::{FormattableCurrency, Locale};
+        use rust_decimal::Decimal;
+
+        assert_eq!(real::USD.display_name(Locale::FrFr, None), "USD");
+        assert_eq!(
+            real::USD.display_name(Locale::FrFr, Some(Decimal::ONE)),
+            "USD"
+        );
+        assert_eq!(
+            real::USD.display_name(Locale::FrFr, Some(Decimal::from(2))),
+            "USDs"
+        );
+    }
+
+    #[test]
+    fn symbol_variants_default_to_symbol() {
+        assert_eq!(real::USD.disambiguated_symbol, "US$");
+        assert_eq!(magic::FOO.narrow_symbol, "F");
+        assert_eq!(magic::FOO.disambiguated_symbol, "F");
+    }
+
+    #[test]
+    fn sets_list_currencies_in_alphabetical_order() {
+        assert_eq!(magic::CURRENCIES, [magic::ABC, magic::FOO, magic::ZED]);
+        assert_eq!(
+            magic::iter().map(|c| c.code).collect::<Vec<_>>(),
+            ["abc", "FOO", "ZED"]
+        );
+        assert_eq!(real::CURRENCIES, [real::USD]);
+    }
+
+    #[test]
+    fn sets_filter_currencies() {
+        use crate::Locale;
+
+        assert_eq!(
+            magic::with_exponent(3).collect::<Vec<_>>(),
+            [magic::ABC, magic::FOO]
+        );
+        assert_eq!(
+            magic::with_locale(Locale::DeDe).collect::<Vec<_>>(),
+            [magic::ZED]
+        );
+        assert_eq!(
+            magic::with_symbol_first(true).collect::<Vec<_>>(),
+            [magic::FOO]
+        );
+        assert_eq!(magic::with_exponent(8).count(), 0);
+    }
+
+    #[test]
+    fn codes_sort_without_case() {
+        assert!(crate::__code_lt("abc", "ABD"));
+        assert!(crate::__code_lt("ABC", "abc"));
+        assert!(crate::__code_lt("AB", "abc"));
+        assert!(!crate::__code_lt("USD", "USD"));
+        assert!(!crate::__code_lt("usd", "EUR"));
+    }
 }
@@ -7,6 +7,7 @@ define_currency_set!(
             code: "BTC",
             exponent: 8,
             locale: EnUs,
+            minor_unit_name: "Satoshi",
             minor_units: 100_000_000,
             name: "Bitcoin",
             symbol: "₿",
@@ -34,6 +35,7 @@ define_currency_set!(
             code: "ETH",
             exponent: 18,
             locale: EnUs,
+            minor_unit_name: "Wei",
             minor_units: 1_000_000_000_000_000_000,
             name: "Ethereum",
             symbol: "ETH",
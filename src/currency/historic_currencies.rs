@@ -0,0 +1,880 @@
+/// Historic ISO-4217 Currency Set, from list three of the standard.
+pub mod historic {
+    use crate::{iso, FormattableCurrency, Locale, Locale::*, Money, MoneyError};
+    use rust_decimal::Decimal;
+    use std::fmt;
+
+    /// Represents a single withdrawn ISO-4217 currency (e.g. DEM).
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub struct Currency {
+        pub iso_alpha_code: &'static str,
+        pub exponent: u32,
+        pub iso_numeric_code: &'static str,
+        pub locale: Locale,
+        pub minor_units: u64,
+        pub name: &'static str,
+        pub plural_name: &'static str,
+        pub symbol: &'static str,
+        pub symbol_first: bool,
+        /// The month the currency was introduced, if it is known.
+        pub introduced: Option<YearMonth>,
+        /// The month the currency was withdrawn, as listed by ISO 4217.
+        pub withdrawn: YearMonth,
+        /// The currency which replaced it, and how many units of this currency make one unit of it.
+        ///
+        /// Currencies without a fixed conversion (e.g. CSK, which was split in two) have none.
+        pub successor: Option<(Successor, Decimal)>,
+    }
+
+    /// The currency which replaced a historic currency.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum Successor {
+        Iso(&'static iso::Currency),
+        /// A currency which was itself withdrawn later (e.g. VEF, which replaced VEB).
+        Historic(&'static Currency),
+    }
+
+    /// A month of a year, which is the precision of ISO 4217 withdrawal dates.
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+    pub struct YearMonth {
+        pub year: u16,
+        pub month: u8,
+    }
+
+    impl YearMonth {
+        pub const fn new(year: u16, month: u8) -> YearMonth {
+            YearMonth { year, month }
+        }
+    }
+
+    impl fmt::Display for YearMonth {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{:04}-{:02}", self.year, self.month)
+        }
+    }
+
+    impl Currency {
+        /// Returns whether the currency was in use during a month, from its introduction until before its
+        /// withdrawal. Currencies without a known introduction are in use at any month before their withdrawal.
+        pub fn was_in_use(&self, date: YearMonth) -> bool {
+            self.introduced.is_none_or(|introduced| introduced <= date) && date < self.withdrawn
+        }
+    }
+
+    impl FormattableCurrency for Currency {
+        fn to_string(&self) -> String {
+            self.code().to_string()
+        }
+
+        fn exponent(&self) -> u32 {
+            self.exponent
+        }
+
+        fn code(&self) -> &'static str {
+            self.iso_alpha_code
+        }
+
+        fn locale(&self) -> Locale {
+            self.locale
+        }
+
+        fn minor_units(&self) -> u64 {
+            self.minor_units
+        }
+
+        fn numeric_code(&self) -> Option<&'static str> {
+            Some(self.iso_numeric_code)
+        }
+
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn plural_name(&self) -> &'static str {
+            self.plural_name
+        }
+
+        fn symbol(&self) -> &'static str {
+            self.symbol
+        }
+
+        fn narrow_symbol(&self) -> &'static str {
+            self.symbol
+        }
+
+        fn disambiguated_symbol(&self) -> &'static str {
+            self.iso_alpha_code
+        }
+
+        fn symbol_first(&self) -> bool {
+            self.symbol_first
+        }
+    }
+
+    impl fmt::Display for Currency {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.iso_alpha_code)
+        }
+    }
+
+    /// Converts an amount of a historic currency into the active ISO currency which replaced it, following
+    /// redenominations through other historic currencies (e.g. ZWD to ZWN, ZWR and then ZWL).
+    ///
+    /// The amount is not rounded. Returns `InvalidCurrency` if the currency has no fixed successor.
+    pub fn convert(
+        money: &Money<'_, Currency>,
+    ) -> Result<Money<'static, iso::Currency>, MoneyError> {
+        let mut amount = *money.amount();
+        let mut currency = money.currency();
+        loop {
+            let (successor, factor) = currency.successor.ok_or(MoneyError::InvalidCurrency)?;
+            amount /= factor;
+            match successor {
+                Successor::Iso(successor) => return Ok(Money::from_decimal(amount, successor)),
+                Successor::Historic(successor) => currency = successor,
+            }
+        }
+    }
+
+    /// Returns the historic currencies which were in use during a month, in alphabetical order of their codes.
+    pub fn in_use(date: YearMonth) -> impl Iterator<Item = &'static Currency> {
+        CURRENCIES
+            .iter()
+            .copied()
+            .filter(move |currency| currency.was_in_use(date))
+    }
+
+    const fn factor(mantissa: u64, scale: u32) -> Decimal {
+        Decimal::from_parts(mantissa as u32, (mantissa >> 32) as u32, 0, false, scale)
+    }
+
+    macro_rules! define_historic {
+    (
+      $(
+            $currency:ident: {
+                exponent: $exp:expr,
+                introduced: $introduced:expr,
+                iso_alpha_code: $alpha_code:expr,
+                iso_numeric_code: $num_code:expr,
+                locale: $loc:expr,
+                minor_units: $min_dem:expr,
+                name: $name:expr,
+                plural_name: $plural:expr,
+                successor: $successor:expr,
+                symbol: $sym:expr,
+                symbol_first: $sym_first:expr,
+                withdrawn: $withdrawn:expr,
+            }
+      ),+
+    ) => {
+      $(
+        pub const $currency: &'static Currency = &Currency {
+            iso_alpha_code: $alpha_code,
+            iso_numeric_code: $num_code,
+            exponent: $exp,
+            locale: $loc,
+            minor_units: $min_dem,
+            name: $name,
+            plural_name: $plural,
+            symbol: $sym,
+            symbol_first: $sym_first,
+            introduced: $introduced,
+            withdrawn: $withdrawn,
+            successor: $successor,
+        };
+      )+
+
+      /// Returns the historic currency with an alphabetic code, ignoring case.
+      pub fn find(code: &str) -> Option<&'static Currency> {
+        match code {
+          $($alpha_code => (Some($currency)),)+
+          _ => $crate::__find_ignore_case(CURRENCIES, code, |currency| currency.iso_alpha_code),
+        }
+      }
+
+      pub fn find_by_num_code(code: &str) -> Option<&'static Currency> {
+        match code {
+          $($num_code => (Some($currency)),)+
+          _ => None,
+        }
+      }
+
+      /// Every historic currency, in alphabetical order of their codes.
+      pub const CURRENCIES: &[&Currency] =
+        &$crate::__sorted_by!([$($currency),+], currency => currency.iso_alpha_code);
+    };
+  }
+
+    define_historic!(
+        ATS : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1945, 12)),
+            iso_alpha_code: "ATS",
+            iso_numeric_code: "040",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Austrian Schilling",
+            plural_name: "Austrian Schillings",
+            successor: Some((Successor::Iso(iso::EUR), factor(137603, 4))),
+            symbol: "S",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        AZM : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1992, 8)),
+            iso_alpha_code: "AZM",
+            iso_numeric_code: "031",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Azerbaijani Manat",
+            plural_name: "Azerbaijani Manats",
+            successor: Some((Successor::Iso(iso::AZN), factor(5000, 0))),
+            symbol: "man.",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2006, 1),
+        },
+        BEF : {
+            exponent: 0,
+            introduced: None,
+            iso_alpha_code: "BEF",
+            iso_numeric_code: "056",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Belgian Franc",
+            plural_name: "Belgian Francs",
+            successor: Some((Successor::Iso(iso::EUR), factor(403399, 4))),
+            symbol: "fr.",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        BGL : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "BGL",
+            iso_numeric_code: "100",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Bulgarian Lev",
+            plural_name: "Bulgarian Leva",
+            successor: Some((Successor::Iso(iso::BGN), factor(1000, 0))),
+            symbol: "лв",
+            symbol_first: false,
+            withdrawn: YearMonth::new(1999, 7),
+        },
+        BYR : {
+            exponent: 0,
+            introduced: Some(YearMonth::new(2000, 1)),
+            iso_alpha_code: "BYR",
+            iso_numeric_code: "974",
+            locale: EnBy,
+            minor_units: 100,
+            name: "Belarusian Ruble",
+            plural_name: "Belarusian Rubles",
+            successor: Some((Successor::Iso(iso::BYN), factor(10000, 0))),
+            symbol: "Br",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2017, 1),
+        },
+        CSK : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "CSK",
+            iso_numeric_code: "200",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Czechoslovak Koruna",
+            plural_name: "Czechoslovak Korunas",
+            successor: None,
+            symbol: "Kčs",
+            symbol_first: false,
+            withdrawn: YearMonth::new(1993, 3),
+        },
+        CYP : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "CYP",
+            iso_numeric_code: "196",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Cypriot Pound",
+            plural_name: "Cypriot Pounds",
+            successor: Some((Successor::Iso(iso::EUR), factor(585274, 6))),
+            symbol: "£",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2008, 1),
+        },
+        DEM : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1948, 6)),
+            iso_alpha_code: "DEM",
+            iso_numeric_code: "276",
+            locale: DeDe,
+            minor_units: 100,
+            name: "Deutsche Mark",
+            plural_name: "Deutsche Marks",
+            successor: Some((Successor::Iso(iso::EUR), factor(195583, 5))),
+            symbol: "DM",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        EEK : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1992, 6)),
+            iso_alpha_code: "EEK",
+            iso_numeric_code: "233",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Estonian Kroon",
+            plural_name: "Estonian Kroons",
+            successor: Some((Successor::Iso(iso::EUR), factor(156466, 4))),
+            symbol: "kr",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2011, 1),
+        },
+        ESP : {
+            exponent: 0,
+            introduced: None,
+            iso_alpha_code: "ESP",
+            iso_numeric_code: "724",
+            locale: EsEs,
+            minor_units: 1,
+            name: "Spanish Peseta",
+            plural_name: "Spanish Pesetas",
+            successor: Some((Successor::Iso(iso::EUR), factor(166386, 3))),
+            symbol: "₧",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        FIM : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1963, 1)),
+            iso_alpha_code: "FIM",
+            iso_numeric_code: "246",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Finnish Markka",
+            plural_name: "Finnish Markkas",
+            successor: Some((Successor::Iso(iso::EUR), factor(594573, 5))),
+            symbol: "mk",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        FRF : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1960, 1)),
+            iso_alpha_code: "FRF",
+            iso_numeric_code: "250",
+            locale: FrFr,
+            minor_units: 100,
+            name: "French Franc",
+            plural_name: "French Francs",
+            successor: Some((Successor::Iso(iso::EUR), factor(655957, 5))),
+            symbol: "F",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        GHC : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "GHC",
+            iso_numeric_code: "288",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Ghanaian Cedi",
+            plural_name: "Ghanaian Cedis",
+            successor: Some((Successor::Iso(iso::GHS), factor(10000, 0))),
+            symbol: "₵",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2007, 7),
+        },
+        GRD : {
+            exponent: 0,
+            introduced: None,
+            iso_alpha_code: "GRD",
+            iso_numeric_code: "300",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Greek Drachma",
+            plural_name: "Greek Drachmas",
+            successor: Some((Successor::Iso(iso::EUR), factor(340750, 3))),
+            symbol: "₯",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        HRK : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1994, 5)),
+            iso_alpha_code: "HRK",
+            iso_numeric_code: "191",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Croatian Kuna",
+            plural_name: "Croatian Kunas",
+            successor: Some((Successor::Iso(iso::EUR), factor(753450, 5))),
+            symbol: "kn",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2023, 1),
+        },
+        IEP : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "IEP",
+            iso_numeric_code: "372",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Irish Pound",
+            plural_name: "Irish Pounds",
+            successor: Some((Successor::Iso(iso::EUR), factor(787564, 6))),
+            symbol: "£",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        ITL : {
+            exponent: 0,
+            introduced: None,
+            iso_alpha_code: "ITL",
+            iso_numeric_code: "380",
+            locale: ItIt,
+            minor_units: 1,
+            name: "Italian Lira",
+            plural_name: "Italian Lire",
+            successor: Some((Successor::Iso(iso::EUR), factor(193627, 2))),
+            symbol: "₤",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        LTL : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1993, 6)),
+            iso_alpha_code: "LTL",
+            iso_numeric_code: "440",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Lithuanian Litas",
+            plural_name: "Lithuanian Litai",
+            successor: Some((Successor::Iso(iso::EUR), factor(345280, 5))),
+            symbol: "Lt",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2015, 1),
+        },
+        LUF : {
+            exponent: 0,
+            introduced: None,
+            iso_alpha_code: "LUF",
+            iso_numeric_code: "442",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Luxembourg Franc",
+            plural_name: "Luxembourg Francs",
+            successor: Some((Successor::Iso(iso::EUR), factor(403399, 4))),
+            symbol: "fr.",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        LVL : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1993, 3)),
+            iso_alpha_code: "LVL",
+            iso_numeric_code: "428",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Latvian Lats",
+            plural_name: "Latvian Lati",
+            successor: Some((Successor::Iso(iso::EUR), factor(702804, 6))),
+            symbol: "Ls",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2014, 1),
+        },
+        MRO : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1973, 6)),
+            iso_alpha_code: "MRO",
+            iso_numeric_code: "478",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Mauritanian Ouguiya",
+            plural_name: "Mauritanian Ouguiyas",
+            successor: Some((Successor::Iso(iso::MRU), factor(10, 0))),
+            symbol: "UM",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2018, 1),
+        },
+        MTL : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "MTL",
+            iso_numeric_code: "470",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Maltese Lira",
+            plural_name: "Maltese Liri",
+            successor: Some((Successor::Iso(iso::EUR), factor(4293, 4))),
+            symbol: "Lm",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2008, 1),
+        },
+        MXP : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "MXP",
+            iso_numeric_code: "484",
+            locale: EsMx,
+            minor_units: 100,
+            name: "Mexican Peso",
+            plural_name: "Mexican Pesos",
+            successor: Some((Successor::Iso(iso::MXN), factor(1000, 0))),
+            symbol: "$",
+            symbol_first: true,
+            withdrawn: YearMonth::new(1993, 1),
+        },
+        MZM : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "MZM",
+            iso_numeric_code: "508",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Mozambican Metical",
+            plural_name: "Mozambican Meticais",
+            successor: Some((Successor::Iso(iso::MZN), factor(1000, 0))),
+            symbol: "MT",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2006, 6),
+        },
+        NLG : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "NLG",
+            iso_numeric_code: "528",
+            locale: NlNl,
+            minor_units: 100,
+            name: "Dutch Guilder",
+            plural_name: "Dutch Guilders",
+            successor: Some((Successor::Iso(iso::EUR), factor(220371, 5))),
+            symbol: "ƒ",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        PTE : {
+            exponent: 0,
+            introduced: None,
+            iso_alpha_code: "PTE",
+            iso_numeric_code: "620",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Portuguese Escudo",
+            plural_name: "Portuguese Escudos",
+            successor: Some((Successor::Iso(iso::EUR), factor(200482, 3))),
+            symbol: "Esc",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2002, 3),
+        },
+        ROL : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "ROL",
+            iso_numeric_code: "642",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Romanian Leu",
+            plural_name: "Romanian Lei",
+            successor: Some((Successor::Iso(iso::RON), factor(10000, 0))),
+            symbol: "ROL",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2005, 6),
+        },
+        RUR : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1992, 1)),
+            iso_alpha_code: "RUR",
+            iso_numeric_code: "810",
+            locale: RuRu,
+            minor_units: 100,
+            name: "Russian Ruble",
+            plural_name: "Russian Rubles",
+            successor: Some((Successor::Iso(iso::RUB), factor(1000, 0))),
+            symbol: "р.",
+            symbol_first: false,
+            withdrawn: YearMonth::new(1998, 1),
+        },
+        SDD : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1992, 6)),
+            iso_alpha_code: "SDD",
+            iso_numeric_code: "736",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Sudanese Dinar",
+            plural_name: "Sudanese Dinars",
+            successor: Some((Successor::Iso(iso::SDG), factor(100, 0))),
+            symbol: "SD",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2007, 7),
+        },
+        SIT : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1991, 10)),
+            iso_alpha_code: "SIT",
+            iso_numeric_code: "705",
+            locale: EnEu,
+            minor_units: 100,
+            name: "Slovenian Tolar",
+            plural_name: "Slovenian Tolars",
+            successor: Some((Successor::Iso(iso::EUR), factor(239640, 3))),
+            symbol: "SIT",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2007, 1),
+        },
+        SKK : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1993, 2)),
+            iso_alpha_code: "SKK",
+            iso_numeric_code: "703",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Slovak Koruna",
+            plural_name: "Slovak Korunas",
+            successor: Some((Successor::Iso(iso::EUR), factor(301260, 4))),
+            symbol: "Sk",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2009, 1),
+        },
+        SRG : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "SRG",
+            iso_numeric_code: "740",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Surinamese Guilder",
+            plural_name: "Surinamese Guilders",
+            successor: Some((Successor::Iso(iso::SRD), factor(1000, 0))),
+            symbol: "ƒ",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2004, 1),
+        },
+        STD : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "STD",
+            iso_numeric_code: "678",
+            locale: EnUs,
+            minor_units: 100,
+            name: "São Tomé and Príncipe Dobra",
+            plural_name: "São Tomé and Príncipe Dobras",
+            successor: Some((Successor::Iso(iso::STN), factor(1000, 0))),
+            symbol: "Db",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2018, 1),
+        },
+        TMM : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1993, 11)),
+            iso_alpha_code: "TMM",
+            iso_numeric_code: "795",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Turkmenistani Manat",
+            plural_name: "Turkmenistani Manats",
+            successor: Some((Successor::Iso(iso::TMT), factor(5000, 0))),
+            symbol: "m",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2009, 1),
+        },
+        TRL : {
+            exponent: 0,
+            introduced: None,
+            iso_alpha_code: "TRL",
+            iso_numeric_code: "792",
+            locale: EnUs,
+            minor_units: 1,
+            name: "Turkish Lira",
+            plural_name: "Turkish Liras",
+            successor: Some((Successor::Iso(iso::TRY), factor(1000000, 0))),
+            symbol: "TL",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2005, 1),
+        },
+        VEB : {
+            exponent: 2,
+            introduced: None,
+            iso_alpha_code: "VEB",
+            iso_numeric_code: "862",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Venezuelan Bolívar",
+            plural_name: "Venezuelan Bolívares",
+            successor: Some((Successor::Historic(VEF), factor(1000, 0))),
+            symbol: "Bs",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2008, 1),
+        },
+        VEF : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(2008, 1)),
+            iso_alpha_code: "VEF",
+            iso_numeric_code: "937",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Venezuelan Bolívar Fuerte",
+            plural_name: "Venezuelan Bolívares Fuertes",
+            successor: Some((Successor::Iso(iso::VES), factor(100000, 0))),
+            symbol: "Bs.F.",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2018, 8),
+        },
+        YUM : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1994, 1)),
+            iso_alpha_code: "YUM",
+            iso_numeric_code: "891",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Yugoslav Dinar",
+            plural_name: "Yugoslav Dinars",
+            successor: None,
+            symbol: "din.",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2003, 7),
+        },
+        ZMK : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1968, 1)),
+            iso_alpha_code: "ZMK",
+            iso_numeric_code: "894",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Zambian Kwacha",
+            plural_name: "Zambian Kwachas",
+            successor: Some((Successor::Iso(iso::ZMW), factor(1000, 0))),
+            symbol: "ZK",
+            symbol_first: false,
+            withdrawn: YearMonth::new(2013, 1),
+        },
+        ZWD : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(1980, 4)),
+            iso_alpha_code: "ZWD",
+            iso_numeric_code: "716",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Zimbabwean Dollar",
+            plural_name: "Zimbabwean Dollars",
+            successor: Some((Successor::Historic(ZWN), factor(1000, 0))),
+            symbol: "Z$",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2006, 8),
+        },
+        ZWN : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(2006, 8)),
+            iso_alpha_code: "ZWN",
+            iso_numeric_code: "942",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Zimbabwean Dollar",
+            plural_name: "Zimbabwean Dollars",
+            successor: Some((Successor::Historic(ZWR), factor(10000000000, 0))),
+            symbol: "Z$",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2008, 8),
+        },
+        ZWR : {
+            exponent: 2,
+            introduced: Some(YearMonth::new(2008, 8)),
+            iso_alpha_code: "ZWR",
+            iso_numeric_code: "935",
+            locale: EnUs,
+            minor_units: 100,
+            name: "Zimbabwean Dollar",
+            plural_name: "Zimbabwean Dollars",
+            successor: Some((Successor::Iso(iso::ZWL), factor(1000000000000, 0))),
+            symbol: "Z$",
+            symbol_first: true,
+            withdrawn: YearMonth::new(2009, 2),
+        }
+
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::historic::{self, Successor, YearMonth};
+    use crate::{iso, Money};
+    use rust_decimal_macros::*;
+
+    #[test]
+    fn historic_currencies_have_validity_periods() {
+        let dem = historic::find("dem").unwrap();
+        assert_eq!(dem.withdrawn, YearMonth::new(2002, 3));
+        assert_eq!(dem.withdrawn.to_string(), "2002-03");
+        assert!(dem.was_in_use(YearMonth::new(1990, 10)));
+        assert!(dem.was_in_use(YearMonth::new(2002, 2)));
+        assert!(!dem.was_in_use(YearMonth::new(2002, 3)));
+        assert!(!dem.was_in_use(YearMonth::new(1948, 5)));
+        assert_eq!(historic::find_by_num_code("276"), Some(dem));
+
+        let in_2007 = historic::in_use(YearMonth::new(2007, 6)).collect::<Vec<_>>();
+        assert!(in_2007.contains(&historic::CYP) && in_2007.contains(&historic::ZWN));
+        assert!(!in_2007.contains(&historic::DEM) && !in_2007.contains(&historic::ZWD));
+    }
+
+    #[test]
+    fn historic_currencies_are_not_active() {
+        assert!(historic::CURRENCIES
+            .windows(2)
+            .all(|pair| pair[0].iso_alpha_code < pair[1].iso_alpha_code));
+        for currency in historic::CURRENCIES {
+            assert_eq!(iso::find(currency.iso_alpha_code), None, "{}", currency);
+            assert!(currency
+                .introduced
+                .is_none_or(|date| date < currency.withdrawn));
+        }
+    }
+
+    #[test]
+    fn historic_amounts_convert_to_their_successor() {
+        assert_eq!(
+            historic::DEM.successor,
+            Some((Successor::Iso(iso::EUR), dec!(1.95583)))
+        );
+
+        let euros = historic::convert(&Money::from_major(1_000, historic::DEM)).unwrap();
+        assert_eq!(euros.currency(), iso::EUR);
+        assert_eq!(
+            euros.round(2, crate::Round::HalfEven).amount(),
+            &dec!(511.29)
+        );
+
+        let lire = historic::convert(&Money::from_major(1_936_270, historic::ITL)).unwrap();
+        assert_eq!(lire, Money::from_major(1_000, iso::EUR));
+
+        let bolivares = historic::convert(&Money::from_major(100_000_000, historic::VEB)).unwrap();
+        assert_eq!(bolivares, Money::from_major(1, iso::VES));
+
+        let zimbabwe =
+            Money::from_str("10,000,000,000,000,000,000,000,000", historic::ZWD).unwrap();
+        assert_eq!(
+            historic::convert(&zimbabwe).unwrap(),
+            Money::from_major(1, iso::ZWL)
+        );
+
+        assert_eq!(
+            historic::convert(&Money::from_major(1, historic::CSK)),
+            Err(crate::MoneyError::InvalidCurrency)
+        );
+    }
+
+    #[test]
+    fn historic_money_formats() {
+        assert_eq!(
+            Money::from_major(1_000, historic::DEM).to_string(),
+            "1.000\u{a0}DM"
+        );
+        assert_eq!(
+            format!("{:#}", Money::from_major(5, historic::FRF)),
+            "5\u{a0}FRF"
+        );
+    }
+}
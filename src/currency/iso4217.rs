@@ -0,0 +1,60 @@
+//! Reads list one of ISO 4217, the table of current currency codes vendored in `data/iso4217/list-one.xml`.
+//!
+//! Shared by the test which keeps `iso` in sync with the list, and by `examples/iso4217.rs`, which generates the
+//! `define_iso!` table from it.
+
+/// A currency of list one.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ListCurrency {
+    pub code: String,
+    pub numeric_code: String,
+    /// The number of decimal places, or None for codes without a minor unit ("N.A.", e.g. XAU).
+    pub minor_units: Option<u32>,
+    pub name: String,
+    pub is_fund: bool,
+}
+
+/// Returns the currencies of the list, once per code and sorted by code. Entries without a currency
+/// (e.g. ANTARCTICA) are skipped.
+pub fn currencies(xml: &str) -> Vec<ListCurrency> {
+    let mut currencies: Vec<ListCurrency> = Vec::new();
+    for entry in xml.split("<CcyNtry>").skip(1) {
+        let Some(code) = element(entry, "Ccy") else {
+            continue;
+        };
+        if currencies.iter().any(|currency| currency.code == code) {
+            continue;
+        }
+
+        let minor_units = element(entry, "CcyMnrUnts").expect("Currencies have minor units");
+        currencies.push(ListCurrency {
+            numeric_code: element(entry, "CcyNbr").expect("Currencies have a numeric code"),
+            minor_units: match minor_units.as_str() {
+                "N.A." => None,
+                digits => Some(digits.parse().expect("Minor units are a number or N.A.")),
+            },
+            name: element(entry, "CcyNm").expect("Currencies have a name"),
+            is_fund: entry.contains("IsFund=\"true\""),
+            code,
+        });
+    }
+    currencies.sort_by(|a, b| a.code.cmp(&b.code));
+    currencies
+}
+
+/// Returns the text of the first element with a tag, which may carry attributes (e.g. `<CcyNm IsFund="true">`).
+fn element(entry: &str, tag: &str) -> Option<String> {
+    let start = entry
+        .find(&format!("<{}>", tag))
+        .or_else(|| entry.find(&format!("<{} ", tag)))?;
+    let text = &entry[start..];
+    let text = &text[text.find('>')? + 1..text.find(&format!("</{}>", tag))?];
+    Some(
+        text.trim()
+            .replace("&apos;", "'")
+            .replace("&quot;", "\"")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&amp;", "&"),
+    )
+}
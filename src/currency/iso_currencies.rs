@@ -8,14 +8,50 @@ pub mod iso {
     pub struct Currency {
         pub iso_alpha_code: &'static str,
         pub exponent: u32,
+        /// The decimal places of cash payments, where the smallest coins have fallen out of use (e.g. 0 for SEK).
+        pub cash_exponent: u32,
         pub iso_numeric_code: &'static str,
+        pub kind: CurrencyKind,
         pub locale: Locale,
         pub minor_units: u64,
+        /// The singular name of the minor unit (e.g. Cent), if the table has one.
+        pub minor_unit_name: Option<&'static str>,
         pub name: &'static str,
+        pub plural_name: &'static str,
         pub symbol: &'static str,
+        pub narrow_symbol: &'static str,
+        pub disambiguated_symbol: &'static str,
         pub symbol_first: bool,
     }
 
+    /// What an ISO-4217 code stands for.
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+    pub enum CurrencyKind {
+        /// A currency in circulation, used to price goods (e.g. USD).
+        Regular,
+        /// A fund code, a unit of account tied to a regular currency (e.g. CLF, USN).
+        Fund,
+        /// A troy ounce of a precious metal (e.g. XAU).
+        Metal,
+        /// A unit of account (e.g. XDR), a bond market unit (e.g. XBA), the testing code XTS or no currency (XXX).
+        Special,
+    }
+
+    impl CurrencyKind {
+        /// Returns whether ISO 4217 defines a minor unit for codes of this kind. Metals and special codes have none
+        /// ("N.A."), so their amounts are not rounded to an exponent.
+        pub fn has_minor_unit(self) -> bool {
+            matches!(self, CurrencyKind::Regular | CurrencyKind::Fund)
+        }
+    }
+
+    impl Currency {
+        /// Returns whether the currency can be used to price goods, unlike fund, metal and special codes.
+        pub fn is_regular(&self) -> bool {
+            self.kind == CurrencyKind::Regular
+        }
+    }
+
     impl FormattableCurrency for Currency {
         fn to_string(&self) -> String {
             self.code().to_string()
@@ -33,13 +69,49 @@ pub mod iso {
             self.locale
         }
 
+        fn minor_units(&self) -> u64 {
+            self.minor_units
+        }
+
+        fn numeric_code(&self) -> Option<&'static str> {
+            Some(self.iso_numeric_code)
+        }
+
+        fn minor_unit_name(&self) -> Option<&'static str> {
+            self.minor_unit_name
+        }
+
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn plural_name(&self) -> &'static str {
+            self.plural_name
+        }
+
         fn symbol(&self) -> &'static str {
             self.symbol
         }
 
+        fn narrow_symbol(&self) -> &'static str {
+            self.narrow_symbol
+        }
+
+        fn disambiguated_symbol(&self) -> &'static str {
+            self.disambiguated_symbol
+        }
+
         fn symbol_first(&self) -> bool {
             self.symbol_first
         }
+
+        fn has_fixed_exponent(&self) -> bool {
+            self.kind.has_minor_unit()
+        }
+
+        fn cash_exponent(&self) -> u32 {
+            self.cash_exponent
+        }
     }
 
     impl fmt::Display for Currency {
@@ -52,12 +124,18 @@ pub mod iso {
     (
       $(
             $currency:ident: {
+                $(cash_exponent: $cash:expr,)?
+                $(disambiguated_symbol: $dis:expr,)?
                 exponent: $exp:expr,
                 iso_alpha_code: $alpha_code:expr,
                 iso_numeric_code: $num_code:expr,
+                $(kind: $kind:expr,)?
                 locale: $loc:expr,
+                $(minor_unit_name: $minor_name:expr,)?
                 minor_units: $min_dem:expr,
                 name: $name:expr,
+                $(narrow_symbol: $narrow:expr,)?
+                plural_name: $plural:expr,
                 symbol: $sym:expr,
                 symbol_first: $sym_first:expr,
             }
@@ -67,19 +145,26 @@ pub mod iso {
         pub const $currency: &'static Currency = &Currency {
             iso_alpha_code: $alpha_code,
             iso_numeric_code: $num_code,
+            kind: $crate::__or_default!(CurrencyKind::Regular $(, $kind)?),
             exponent: $exp,
+            cash_exponent: $crate::__or_default!($exp $(, $cash)?),
             locale: $loc,
             minor_units: $min_dem,
+            minor_unit_name: $crate::__or_default!(None $(, Some($minor_name))?),
             name: $name,
+            plural_name: $plural,
             symbol: $sym,
+            narrow_symbol: $crate::__or_default!($sym $(, $narrow)?),
+            disambiguated_symbol: $crate::__or_default!($sym $(, $dis)?),
             symbol_first: $sym_first,
         };
       )+
 
+      /// Returns the currency with an alphabetic code, ignoring case.
       pub fn find(code: &str) -> Option<&'static Currency> {
         match code {
           $($alpha_code => (Some($currency)),)+
-          _ => None,
+          _ => $crate::__find_ignore_case(CURRENCIES, code, |currency| currency.iso_alpha_code),
         }
       }
 
@@ -89,6 +174,16 @@ pub mod iso {
           _ => None,
         }
       }
+
+      /// Every ISO-4217 currency, in alphabetical order of their codes.
+      pub const CURRENCIES: &[&Currency] =
+        &$crate::__sorted_by!([$($currency),+], currency => currency.iso_alpha_code);
+
+      const BY_SYMBOL: &[&Currency] = &$crate::__sorted_by!(
+        [$($currency),+],
+        currency => currency.iso_alpha_code,
+        currency => currency.symbol
+      );
     };
   }
 
@@ -97,9 +192,11 @@ pub mod iso {
             exponent: 2,
             iso_alpha_code: "AED",
             iso_numeric_code: "784",
-            locale: EnUs,
+            locale: ArAe,
+            minor_unit_name: "Fils",
             minor_units: 25,
             name: "United Arab Emirates Dirham",
+            plural_name: "United Arab Emirates Dirhams",
             symbol: "د.إ",
             symbol_first: false,
         },
@@ -110,36 +207,43 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Afghan Afghani",
+            plural_name: "Afghan Afghanis",
             symbol: "؋",
             symbol_first: false,
         },
         ALL : {
+            disambiguated_symbol: "ALL",
             exponent: 2,
             iso_alpha_code: "ALL",
             iso_numeric_code: "008",
             locale: EnEu,
             minor_units: 1,
             name: "Albanian lek",
+            plural_name: "Albanian lekë",
             symbol: "L",
             symbol_first: false,
         },
         AMD : {
+            cash_exponent: 0,
             exponent: 2,
             iso_alpha_code: "AMD",
             iso_numeric_code: "051",
             locale: EnUs,
             minor_units: 10,
             name: "Armenian Dram",
+            plural_name: "Armenian Drams",
             symbol: "դր.",
             symbol_first: false,
         },
         ANG : {
+            disambiguated_symbol: "ANG",
             exponent: 2,
             iso_alpha_code: "ANG",
             iso_numeric_code: "532",
             locale: EnUs,
             minor_units: 1,
             name: "Netherlands Antillean Gulden",
+            plural_name: "Netherlands Antillean Guldens",
             symbol: "ƒ",
             symbol_first: false,
         },
@@ -150,36 +254,45 @@ pub mod iso {
             locale: EnUs,
             minor_units: 10,
             name: "Angolan Kwanza",
+            plural_name: "Angolan Kwanzas",
             symbol: "Kz",
             symbol_first: false,
         },
         ARS : {
+            disambiguated_symbol: "AR$",
             exponent: 2,
             iso_alpha_code: "ARS",
             iso_numeric_code: "032",
             locale: EnEu,
+            minor_unit_name: "Centavo",
             minor_units: 1,
             name: "Argentine Peso",
+            plural_name: "Argentine Pesos",
             symbol: "$",
             symbol_first: true,
         },
         AUD : {
+            disambiguated_symbol: "A$",
             exponent: 2,
             iso_alpha_code: "AUD",
             iso_numeric_code: "036",
             locale: EnUs,
+            minor_unit_name: "Cent",
             minor_units: 5,
             name: "Australian Dollar",
+            plural_name: "Australian Dollars",
             symbol: "$",
             symbol_first: true,
         },
         AWG : {
+            disambiguated_symbol: "AWG",
             exponent: 2,
             iso_alpha_code: "AWG",
             iso_numeric_code: "533",
             locale: EnUs,
             minor_units: 5,
             name: "Aruban Florin",
+            plural_name: "Aruban Florins",
             symbol: "ƒ",
             symbol_first: false,
         },
@@ -190,6 +303,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Azerbaijani Manat",
+            plural_name: "Azerbaijani Manats",
             symbol: "₼",
             symbol_first: true,
         },
@@ -200,16 +314,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Bosnia and Herzegovina Convertible Mark",
+            plural_name: "Bosnia and Herzegovina Convertible Marks",
             symbol: "KM",
             symbol_first: true,
         },
         BBD : {
+            disambiguated_symbol: "BB$",
             exponent: 2,
             iso_alpha_code: "BBD",
             iso_numeric_code: "052",
             locale: EnUs,
             minor_units: 1,
             name: "Barbadian Dollar",
+            plural_name: "Barbadian Dollars",
             symbol: "$",
             symbol_first: true,
         },
@@ -220,10 +337,10 @@ pub mod iso {
             locale: EnIn,
             minor_units: 1,
             name: "Bangladeshi Taka",
+            plural_name: "Bangladeshi Takas",
             symbol: "৳",
             symbol_first: true,
         },
-
         BGN : {
             exponent: 2,
             iso_alpha_code: "BGN",
@@ -231,6 +348,7 @@ pub mod iso {
             locale: EnIn,
             minor_units: 1,
             name: "Bulgarian Lev",
+            plural_name: "Bulgarian Leva",
             symbol: "лв.",
             symbol_first: false,
         },
@@ -241,37 +359,43 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Bahraini Dinar",
+            plural_name: "Bahraini Dinars",
             symbol: "د.ب",
             symbol_first: true,
         },
         BIF : {
+            disambiguated_symbol: "BIF",
             exponent: 0,
             iso_alpha_code: "BIF",
             iso_numeric_code: "108",
             locale: EnUs,
             minor_units: 100,
             name: "Burundian Franc",
+            plural_name: "Burundian Francs",
             symbol: "Fr",
             symbol_first: false,
         },
-
         BMD : {
+            disambiguated_symbol: "BM$",
             exponent: 2,
             iso_alpha_code: "BMD",
             iso_numeric_code: "060",
             locale: EnUs,
             minor_units: 1,
             name: "Bermudian Dollar",
+            plural_name: "Bermudian Dollars",
             symbol: "$",
             symbol_first: true,
         },
         BND : {
+            disambiguated_symbol: "BN$",
             exponent: 2,
             iso_alpha_code: "BND",
             iso_numeric_code: "096",
             locale: EnUs,
             minor_units: 1,
             name: "Brunei Dollar",
+            plural_name: "Brunei Dollars",
             symbol: "$",
             symbol_first: true,
         },
@@ -282,26 +406,43 @@ pub mod iso {
             locale: EnUs,
             minor_units: 10,
             name: "Bolivian Boliviano",
+            plural_name: "Bolivian Bolivianos",
             symbol: "Bs.",
             symbol_first: true,
         },
+        BOV : {
+            exponent: 2,
+            iso_alpha_code: "BOV",
+            iso_numeric_code: "984",
+            kind: CurrencyKind::Fund,
+            locale: EnUs,
+            minor_units: 100,
+            name: "Bolivian Mvdol",
+            plural_name: "Bolivian Mvdols",
+            symbol: "BOV",
+            symbol_first: false,
+        },
         BRL : {
             exponent: 2,
             iso_alpha_code: "BRL",
             iso_numeric_code: "986",
-            locale: EnUs,
+            locale: PtBr,
+            minor_unit_name: "Centavo",
             minor_units: 5,
             name: "Brazilian real",
+            plural_name: "Brazilian reals",
             symbol: "R$",
             symbol_first: true,
         },
         BSD : {
+            disambiguated_symbol: "BS$",
             exponent: 2,
             iso_alpha_code: "BSD",
             iso_numeric_code: "044",
             locale: EnUs,
             minor_units: 1,
             name: "Bahamian Dollar",
+            plural_name: "Bahamian Dollars",
             symbol: "$",
             symbol_first: true,
         },
@@ -312,166 +453,229 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Bhutanese Ngultrum",
+            plural_name: "Bhutanese Ngultrums",
             symbol: "Nu.",
             symbol_first: false,
         },
         BWP : {
+            disambiguated_symbol: "BWP",
             exponent: 2,
             iso_alpha_code: "BWP",
             iso_numeric_code: "072",
             locale: EnUs,
             minor_units: 5,
             name: "Botswana Pula",
+            plural_name: "Botswana Pulas",
             symbol: "P",
             symbol_first: true,
         },
         BYN : {
+            disambiguated_symbol: "BYN",
             exponent: 2,
             iso_alpha_code: "BYN",
             iso_numeric_code: "933",
             locale: EnBy,
             minor_units: 1,
             name: "Belarusian Ruble",
-            symbol: "Br",
-            symbol_first: false,
-        },
-        BYR : {
-            exponent: 0,
-            iso_alpha_code: "BYR",
-            iso_numeric_code: "974",
-            locale: EnBy,
-            minor_units: 100,
-            name: "Belarusian Ruble",
+            plural_name: "Belarusian Rubles",
             symbol: "Br",
             symbol_first: false,
         },
         BZD : {
+            disambiguated_symbol: "BZ$",
             exponent: 2,
             iso_alpha_code: "BZD",
             iso_numeric_code: "084",
             locale: EnUs,
             minor_units: 1,
             name: "Belize Dollar",
+            plural_name: "Belize Dollars",
             symbol: "$",
             symbol_first: true,
         },
         CAD : {
+            disambiguated_symbol: "CA$",
             exponent: 2,
             iso_alpha_code: "CAD",
             iso_numeric_code: "124",
-            locale: EnUs,
+            locale: EnCa,
+            minor_unit_name: "Cent",
             minor_units: 5,
             name: "Canadian Dollar",
+            plural_name: "Canadian Dollars",
             symbol: "$",
             symbol_first: true,
         },
         CDF : {
+            disambiguated_symbol: "CDF",
             exponent: 2,
             iso_alpha_code: "CDF",
             iso_numeric_code: "976",
             locale: EnUs,
             minor_units: 1,
             name: "Congolese Franc",
+            plural_name: "Congolese Francs",
             symbol: "Fr",
             symbol_first: false,
         },
+        CHE : {
+            exponent: 2,
+            iso_alpha_code: "CHE",
+            iso_numeric_code: "947",
+            kind: CurrencyKind::Fund,
+            locale: DeCh,
+            minor_units: 100,
+            name: "WIR Euro",
+            plural_name: "WIR Euros",
+            symbol: "CHE",
+            symbol_first: false,
+        },
         CHF : {
+            disambiguated_symbol: "CHF",
             exponent: 2,
             iso_alpha_code: "CHF",
             iso_numeric_code: "756",
-            locale: EnUs,
+            locale: DeCh,
+            minor_unit_name: "Rappen",
             minor_units: 5,
             name: "Swiss Franc",
+            plural_name: "Swiss Francs",
             symbol: "Fr",
             symbol_first: true,
         },
+        CHW : {
+            exponent: 2,
+            iso_alpha_code: "CHW",
+            iso_numeric_code: "948",
+            kind: CurrencyKind::Fund,
+            locale: DeCh,
+            minor_units: 100,
+            name: "WIR Franc",
+            plural_name: "WIR Francs",
+            symbol: "CHW",
+            symbol_first: false,
+        },
         CLF : {
             exponent: 4,
             iso_alpha_code: "CLF",
             iso_numeric_code: "990",
+            kind: CurrencyKind::Fund,
             locale: EnEu,
             minor_units: 5,
             name: "Unidad de Fomento",
+            plural_name: "Unidades de Fomento",
             symbol: "UF",
             symbol_first: true,
         },
         CLP : {
+            disambiguated_symbol: "CL$",
             exponent: 0,
             iso_alpha_code: "CLP",
             iso_numeric_code: "152",
             locale: EnEu,
             minor_units: 1,
             name: "Chilean Peso",
+            plural_name: "Chilean Pesos",
             symbol: "$",
             symbol_first: true,
         },
         CNY : {
+            disambiguated_symbol: "CN¥",
             exponent: 2,
             iso_alpha_code: "CNY",
             iso_numeric_code: "156",
-            locale: EnUs,
+            locale: ZhCn,
+            minor_unit_name: "Fen",
             minor_units: 1,
             name: "Chinese Renminbi Yuan",
+            plural_name: "Chinese Renminbi Yuan",
             symbol: "¥",
             symbol_first: true,
         },
         COP : {
+            cash_exponent: 0,
+            disambiguated_symbol: "CO$",
             exponent: 2,
             iso_alpha_code: "COP",
             iso_numeric_code: "170",
             locale: EnEu,
             minor_units: 20,
             name: "Colombian Peso",
+            plural_name: "Colombian Pesos",
             symbol: "$",
             symbol_first: true,
         },
+        COU : {
+            exponent: 2,
+            iso_alpha_code: "COU",
+            iso_numeric_code: "970",
+            kind: CurrencyKind::Fund,
+            locale: EnUs,
+            minor_units: 100,
+            name: "Unidad de Valor Real",
+            plural_name: "Unidades de Valor Real",
+            symbol: "COU",
+            symbol_first: false,
+        },
         CRC : {
+            cash_exponent: 0,
+            disambiguated_symbol: "CRC",
             exponent: 2,
             iso_alpha_code: "CRC",
             iso_numeric_code: "188",
             locale: EnEu,
             minor_units: 500, // TODO - Investigate
             name: "Costa Rican Colón",
+            plural_name: "Costa Rican Colóns",
             symbol: "₡",
             symbol_first: true,
         },
         CUC : {
+            disambiguated_symbol: "CUC$",
             exponent: 2,
             iso_alpha_code: "CUC",
             iso_numeric_code: "931",
             locale: EnUs,
             minor_units: 1,
             name: "Cuban Convertible Peso",
+            plural_name: "Cuban Convertible Pesos",
             symbol: "$",
             symbol_first: false,
         },
         CUP : {
+            disambiguated_symbol: "CU$",
             exponent: 2,
             iso_alpha_code: "CUP",
             iso_numeric_code: "192",
             locale: EnUs,
             minor_units: 1,
             name: "Cuban Peso",
+            plural_name: "Cuban Pesos",
             symbol: "$",
             symbol_first: true,
         },
         CVE : {
+            disambiguated_symbol: "CV$",
             exponent: 2,
             iso_alpha_code: "CVE",
             iso_numeric_code: "132",
             locale: EnUs,
             minor_units: 100,
             name: "Cape Verdean Escudo",
+            plural_name: "Cape Verdean Escudos",
             symbol: "$",
             symbol_first: false,
         },
         CZK : {
+            cash_exponent: 0,
             exponent: 2,
             iso_alpha_code: "CZK",
             iso_numeric_code: "203",
             locale: EnBy,
+            minor_unit_name: "Haléř",
             minor_units: 100,
             name: "Czech Koruna",
+            plural_name: "Czech Korunas",
             symbol: "Kč",
             symbol_first: false,
         },
@@ -482,26 +686,33 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Djiboutian Franc",
+            plural_name: "Djiboutian Francs",
             symbol: "Fdj",
             symbol_first: false,
         },
         DKK : {
+            disambiguated_symbol: "DKK",
             exponent: 2,
             iso_alpha_code: "DKK",
             iso_numeric_code: "208",
             locale: EnEu,
+            minor_unit_name: "Øre",
             minor_units: 50,
             name: "Danish Krone",
+            narrow_symbol: "kr",
+            plural_name: "Danish Kroner",
             symbol: "kr.",
             symbol_first: false,
         },
         DOP : {
+            disambiguated_symbol: "RD$",
             exponent: 2,
             iso_alpha_code: "DOP",
             iso_numeric_code: "214",
             locale: EnUs,
             minor_units: 100,
             name: "Dominican Peso",
+            plural_name: "Dominican Pesos",
             symbol: "$",
             symbol_first: true,
         },
@@ -512,6 +723,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Algerian Dinar",
+            plural_name: "Algerian Dinars",
             symbol: "د.ج",
             symbol_first: false,
         },
@@ -520,8 +732,10 @@ pub mod iso {
             iso_alpha_code: "EGP",
             iso_numeric_code: "818",
             locale: EnUs,
+            minor_unit_name: "Piastre",
             minor_units: 25,
             name: "Egyptian Pound",
+            plural_name: "Egyptian Pounds",
             symbol: "ج.م",
             symbol_first: true,
         },
@@ -532,16 +746,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Eritrean Nakfa",
+            plural_name: "Eritrean Nakfas",
             symbol: "Nfk",
             symbol_first: false,
         },
         ETB : {
+            disambiguated_symbol: "ETB",
             exponent: 2,
             iso_alpha_code: "ETB",
             iso_numeric_code: "230",
             locale: EnUs,
             minor_units: 1,
             name: "Ethiopian Birr",
+            plural_name: "Ethiopian Birrs",
             symbol: "Br",
             symbol_first: false,
         },
@@ -550,28 +767,34 @@ pub mod iso {
             iso_alpha_code: "EUR",
             iso_numeric_code: "978",
             locale: EnEu,
+            minor_unit_name: "Cent",
             minor_units: 1,
             name: "Euro",
+            plural_name: "Euros",
             symbol: "€",
             symbol_first: true,
         },
         FJD : {
+            disambiguated_symbol: "FJ$",
             exponent: 2,
             iso_alpha_code: "FJD",
             iso_numeric_code: "242",
             locale: EnEu,
             minor_units: 5,
             name: "Fijian Dollar",
+            plural_name: "Fijian Dollars",
             symbol: "$",
             symbol_first: false,
         },
         FKP : {
+            disambiguated_symbol: "FK£",
             exponent: 2,
             iso_alpha_code: "FKP",
             iso_numeric_code: "238",
             locale: EnEu,
             minor_units: 1,
             name: "Falkland Pound",
+            plural_name: "Falkland Pounds",
             symbol: "£",
             symbol_first: false,
         },
@@ -580,8 +803,10 @@ pub mod iso {
             iso_alpha_code: "GBP",
             iso_numeric_code: "826",
             locale: EnUs,
+            minor_unit_name: "Penny",
             minor_units: 1,
             name: "British Pound",
+            plural_name: "British Pounds",
             symbol: "£",
             symbol_first: true,
         },
@@ -592,6 +817,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Georgian Lari",
+            plural_name: "Georgian Laris",
             symbol: "ლ",
             symbol_first: false,
         },
@@ -602,16 +828,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Ghanaian Cedi",
+            plural_name: "Ghanaian Cedis",
             symbol: "₵",
             symbol_first: true,
         },
         GIP : {
+            disambiguated_symbol: "GI£",
             exponent: 2,
             iso_alpha_code: "GIP",
             iso_numeric_code: "292",
             locale: EnUs,
             minor_units: 1,
             name: "Gibraltar Pound",
+            plural_name: "Gibraltar Pounds",
             symbol: "£",
             symbol_first: true,
         },
@@ -622,16 +851,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Gambian Dalasi",
+            plural_name: "Gambian Dalasis",
             symbol: "D",
             symbol_first: false,
         },
         GNF : {
+            disambiguated_symbol: "GNF",
             exponent: 0,
             iso_alpha_code: "GNF",
             iso_numeric_code: "324",
             locale: EnUs,
             minor_units: 100,
             name: "Guinean Franc",
+            plural_name: "Guinean Francs",
             symbol: "Fr",
             symbol_first: false,
         },
@@ -642,49 +874,47 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Guatemalan Quetzal",
+            plural_name: "Guatemalan Quetzals",
             symbol: "Q",
             symbol_first: true,
         },
         GYD : {
+            disambiguated_symbol: "GY$",
             exponent: 2,
             iso_alpha_code: "GYD",
             iso_numeric_code: "328",
             locale: EnUs,
             minor_units: 100,
             name: "Guyanese Dollar",
+            plural_name: "Guyanese Dollars",
             symbol: "$",
             symbol_first: false,
         },
         HKD : {
+            disambiguated_symbol: "HK$",
             exponent: 2,
             iso_alpha_code: "HKD",
             iso_numeric_code: "344",
             locale: EnUs,
+            minor_unit_name: "Cent",
             minor_units: 10,
             name: "Hong Kong Dollar",
+            plural_name: "Hong Kong Dollars",
             symbol: "$",
             symbol_first: true,
         },
         HNL : {
+            disambiguated_symbol: "HNL",
             exponent: 2,
             iso_alpha_code: "HNL",
             iso_numeric_code: "340",
             locale: EnUs,
             minor_units: 5,
             name: "Honduran Lempira",
+            plural_name: "Honduran Lempiras",
             symbol: "L",
             symbol_first: true,
         },
-        HRK : {
-            exponent: 2,
-            iso_alpha_code: "HRK",
-            iso_numeric_code: "191",
-            locale: EnEu,
-            minor_units: 1,
-            name: "Croatian Kuna",
-            symbol: "kn",
-            symbol_first: false,
-        },
         HTG : {
             exponent: 2,
             iso_alpha_code: "HTG",
@@ -692,26 +922,31 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Haitian Gourde",
+            plural_name: "Haitian Gourdes",
             symbol: "G",
             symbol_first: false,
         },
         HUF : {
-            exponent: 0,
+            cash_exponent: 0,
+            exponent: 2,
             iso_alpha_code: "HUF",
             iso_numeric_code: "348",
             locale: EnBy,
             minor_units: 5,
             name: "Hungarian Forint",
+            plural_name: "Hungarian Forints",
             symbol: "Ft",
             symbol_first: false,
         },
         IDR : {
+            cash_exponent: 0,
             exponent: 2,
             iso_alpha_code: "IDR",
             iso_numeric_code: "360",
             locale: EnUs,
             minor_units: 5000,
             name: "Indonesian Rupiah",
+            plural_name: "Indonesian Rupiahs",
             symbol: "Rp",
             symbol_first: true,
         },
@@ -719,19 +954,23 @@ pub mod iso {
             exponent: 2,
             iso_alpha_code: "ILS",
             iso_numeric_code: "376",
-            locale: EnUs,
+            locale: HeIl,
+            minor_unit_name: "Agora",
             minor_units: 10,
             name: "Israeli New Sheqel",
+            plural_name: "Israeli New Sheqels",
             symbol: "₪",
-            symbol_first: true,
+            symbol_first: false,
         },
         INR : {
             exponent: 2,
             iso_alpha_code: "INR",
             iso_numeric_code: "356",
             locale: EnIn,
+            minor_unit_name: "Paisa",
             minor_units: 50,
             name: "Indian Rupee",
+            plural_name: "Indian Rupees",
             symbol: "₹",
             symbol_first: true,
         },
@@ -742,36 +981,44 @@ pub mod iso {
             locale: EnUs,
             minor_units: 50000,
             name: "Iraqi Dinar",
+            plural_name: "Iraqi Dinars",
             symbol: "ع.د",
             symbol_first: false,
         },
         IRR : {
+            disambiguated_symbol: "IRR",
             exponent: 2,
             iso_alpha_code: "IRR",
             iso_numeric_code: "364",
             locale: EnUs,
             minor_units: 5000,
             name: "Iranian Rial",
+            plural_name: "Iranian Rials",
             symbol: "﷼",
             symbol_first: true,
         },
         ISK : {
+            disambiguated_symbol: "ISK",
             exponent: 0,
             iso_alpha_code: "ISK",
             iso_numeric_code: "352",
             locale: EnEu,
             minor_units: 1,
             name: "Icelandic Króna",
+            narrow_symbol: "kr",
+            plural_name: "Icelandic Krónur",
             symbol: "kr.",
             symbol_first: true,
         },
         JMD : {
+            disambiguated_symbol: "JM$",
             exponent: 2,
             iso_alpha_code: "JMD",
             iso_numeric_code: "388",
             locale: EnUs,
             minor_units: 1,
             name: "Jamaican Dollar",
+            plural_name: "Jamaican Dollars",
             symbol: "$",
             symbol_first: true,
         },
@@ -782,16 +1029,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Jordanian Dinar",
+            plural_name: "Jordanian Dinars",
             symbol: "د.ا",
             symbol_first: true,
         },
         JPY : {
+            disambiguated_symbol: "JP¥",
             exponent: 0,
             iso_alpha_code: "JPY",
             iso_numeric_code: "392",
-            locale: EnUs,
+            locale: JaJp,
             minor_units: 1,
             name: "Japanese Yen",
+            plural_name: "Japanese Yen",
             symbol: "¥",
             symbol_first: true,
         },
@@ -802,6 +1052,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 50,
             name: "Kenyan Shilling",
+            plural_name: "Kenyan Shillings",
             symbol: "KSh",
             symbol_first: true,
         },
@@ -812,6 +1063,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Kyrgyzstani Som",
+            plural_name: "Kyrgyzstani Soms",
             symbol: "som",
             symbol_first: false,
         },
@@ -822,36 +1074,43 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5000,
             name: "Cambodian Riel",
+            plural_name: "Cambodian Riels",
             symbol: "៛",
             symbol_first: false,
         },
         KMF : {
+            disambiguated_symbol: "KMF",
             exponent: 0,
             iso_alpha_code: "KMF",
             iso_numeric_code: "174",
             locale: EnUs,
             minor_units: 100,
             name: "Comorian Franc",
+            plural_name: "Comorian Francs",
             symbol: "Fr",
             symbol_first: false,
         },
         KPW : {
+            disambiguated_symbol: "KP₩",
             exponent: 2,
             iso_alpha_code: "KPW",
             iso_numeric_code: "408",
             locale: EnUs,
             minor_units: 1,
             name: "North Korean Won",
+            plural_name: "North Korean Won",
             symbol: "₩",
             symbol_first: false,
         },
         KRW : {
+            disambiguated_symbol: "KR₩",
             exponent: 0,
             iso_alpha_code: "KRW",
             iso_numeric_code: "410",
-            locale: EnUs,
+            locale: KoKr,
             minor_units: 1,
             name: "South Korean Won",
+            plural_name: "South Korean Won",
             symbol: "₩",
             symbol_first: true,
         },
@@ -860,18 +1119,22 @@ pub mod iso {
             iso_alpha_code: "KWD",
             iso_numeric_code: "414",
             locale: EnUs,
+            minor_unit_name: "Fils",
             minor_units: 5,
             name: "Kuwaiti Dinar",
+            plural_name: "Kuwaiti Dinars",
             symbol: "د.ك",
             symbol_first: true,
         },
         KYD : {
+            disambiguated_symbol: "KY$",
             exponent: 2,
             iso_alpha_code: "KYD",
             iso_numeric_code: "136",
             locale: EnUs,
             minor_units: 1,
             name: "Cayman Islands Dollar",
+            plural_name: "Cayman Islands Dollars",
             symbol: "$",
             symbol_first: true,
         },
@@ -882,6 +1145,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Kazakhstani Tenge",
+            plural_name: "Kazakhstani Tenges",
             symbol: "₸",
             symbol_first: false,
         },
@@ -892,6 +1156,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 10,
             name: "Lao Kip",
+            plural_name: "Lao Kips",
             symbol: "₭",
             symbol_first: false,
         },
@@ -902,36 +1167,43 @@ pub mod iso {
             locale: EnUs,
             minor_units: 25000,
             name: "Lebanese Pound",
+            plural_name: "Lebanese Pounds",
             symbol: "ل.ل",
             symbol_first: true,
         },
         LKR : {
+            disambiguated_symbol: "LKR",
             exponent: 2,
             iso_alpha_code: "LKR",
             iso_numeric_code: "144",
             locale: EnUs,
             minor_units: 100,
             name: "Sri Lankan Rupee",
+            plural_name: "Sri Lankan Rupees",
             symbol: "₨",
             symbol_first: false,
         },
         LRD : {
+            disambiguated_symbol: "LR$",
             exponent: 2,
             iso_alpha_code: "LRD",
             iso_numeric_code: "430",
             locale: EnUs,
             minor_units: 5,
             name: "Liberian Dollar",
+            plural_name: "Liberian Dollars",
             symbol: "$",
             symbol_first: false,
         },
         LSL : {
+            disambiguated_symbol: "LSL",
             exponent: 2,
             iso_alpha_code: "LSL",
             iso_numeric_code: "426",
             locale: EnUs,
             minor_units: 1,
             name: "Lesotho Loti",
+            plural_name: "Lesotho Maloti",
             symbol: "L",
             symbol_first: false,
         },
@@ -942,6 +1214,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 50,
             name: "Libyan Dinar",
+            plural_name: "Libyan Dinars",
             symbol: "ل.د",
             symbol_first: false,
         },
@@ -952,26 +1225,30 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Moroccan Dirham",
+            plural_name: "Moroccan Dirhams",
             symbol: "د.م.",
             symbol_first: false,
         },
         MDL : {
+            disambiguated_symbol: "MDL",
             exponent: 2,
             iso_alpha_code: "MDL",
             iso_numeric_code: "498",
             locale: EnUs,
             minor_units: 1,
             name: "Moldovan Leu",
+            plural_name: "Moldovan Lei",
             symbol: "L",
             symbol_first: false,
         },
         MGA : {
-            exponent: 1, // TODO - exponent is 1/5th need to represent somehow
+            exponent: 2,
             iso_alpha_code: "MGA",
             iso_numeric_code: "969",
             locale: EnUs,
             minor_units: 1,
             name: "Malagasy Ariary",
+            plural_name: "Malagasy Ariaries",
             symbol: "Ar",
             symbol_first: true,
         },
@@ -982,56 +1259,67 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Macedonian Denar",
+            plural_name: "Macedonian Denars",
             symbol: "ден",
             symbol_first: false,
         },
         MMK : {
+            disambiguated_symbol: "MMK",
             exponent: 2,
             iso_alpha_code: "MMK",
             iso_numeric_code: "104",
             locale: EnUs,
             minor_units: 50,
             name: "Myanmar Kyat",
+            plural_name: "Myanmar Kyats",
             symbol: "K",
             symbol_first: false,
         },
         MNT : {
+            cash_exponent: 0,
             exponent: 2,
             iso_alpha_code: "MNT",
             iso_numeric_code: "496",
             locale: EnUs,
             minor_units: 2000,
             name: "Mongolian Tögrög",
+            plural_name: "Mongolian Tögrögs",
             symbol: "₮",
             symbol_first: false,
         },
         MOP : {
+            disambiguated_symbol: "MOP",
             exponent: 2,
             iso_alpha_code: "MOP",
             iso_numeric_code: "446",
             locale: EnUs,
             minor_units: 10,
             name: "Macanese Pataca",
+            plural_name: "Macanese Patacas",
             symbol: "P",
             symbol_first: false,
         },
         MRU : {
-            exponent: 1, // TODO - exponent problem of 5
+            exponent: 2,
             iso_alpha_code: "MRU",
             iso_numeric_code: "929",
             locale: EnUs,
             minor_units: 1,
             name: "Mauritanian Ouguiya",
+            plural_name: "Mauritanian Ouguiyas",
             symbol: "UM",
             symbol_first: false,
         },
         MUR : {
+            cash_exponent: 0,
+            disambiguated_symbol: "MUR",
             exponent: 2,
             iso_alpha_code: "MUR",
             iso_numeric_code: "480",
             locale: EnUs,
             minor_units: 100,
             name: "Mauritian Rupee",
+            plural_name: "Mauritian Rupees",
             symbol: "₨",
             symbol_first: true,
         },
@@ -1042,6 +1330,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Maldivian Rufiyaa",
+            plural_name: "Maldivian Rufiyaas",
             symbol: "MVR",
             symbol_first: false,
         },
@@ -1052,19 +1341,35 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Malawian Kwacha",
+            plural_name: "Malawian Kwachas",
             symbol: "MK",
             symbol_first: false,
         },
         MXN : {
+            disambiguated_symbol: "MX$",
             exponent: 2,
             iso_alpha_code: "MXN",
             iso_numeric_code: "484",
-            locale: EnUs,
+            locale: EsMx,
+            minor_unit_name: "Centavo",
             minor_units: 5,
             name: "Mexican Peso",
+            plural_name: "Mexican Pesos",
             symbol: "$",
             symbol_first: true,
         },
+        MXV : {
+            exponent: 2,
+            iso_alpha_code: "MXV",
+            iso_numeric_code: "979",
+            kind: CurrencyKind::Fund,
+            locale: EsMx,
+            minor_units: 100,
+            name: "Mexican Unidad de Inversion",
+            plural_name: "Mexican Unidades de Inversion",
+            symbol: "UDI",
+            symbol_first: false,
+        },
         MYR : {
             exponent: 2,
             iso_alpha_code: "MYR",
@@ -1072,6 +1377,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Malaysian Ringgit",
+            plural_name: "Malaysian Ringgits",
             symbol: "RM",
             symbol_first: true,
         },
@@ -1082,16 +1388,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Mozambican Metical",
+            plural_name: "Mozambican Meticals",
             symbol: "MTn",
             symbol_first: true,
         },
         NAD : {
+            disambiguated_symbol: "NA$",
             exponent: 2,
             iso_alpha_code: "NAD",
             iso_numeric_code: "516",
             locale: EnUs,
             minor_units: 5,
             name: "Namibian Dollar",
+            plural_name: "Namibian Dollars",
             symbol: "$",
             symbol_first: false,
         },
@@ -1102,6 +1411,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 50,
             name: "Nigerian Naira",
+            plural_name: "Nigerian Nairas",
             symbol: "₦",
             symbol_first: true,
         },
@@ -1112,16 +1422,21 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Nicaraguan Córdoba",
+            plural_name: "Nicaraguan Córdobas",
             symbol: "C$",
             symbol_first: true,
         },
         NOK : {
+            cash_exponent: 0,
+            disambiguated_symbol: "NOK",
             exponent: 2,
             iso_alpha_code: "NOK",
             iso_numeric_code: "578",
             locale: EnUs,
+            minor_unit_name: "Øre",
             minor_units: 100,
             name: "Norwegian Krone",
+            plural_name: "Norwegian Kroner",
             symbol: "kr",
             symbol_first: false,
         },
@@ -1132,16 +1447,20 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Nepalese Rupee",
+            plural_name: "Nepalese Rupees",
             symbol: "रु",
             symbol_first: true,
         },
         NZD : {
+            disambiguated_symbol: "NZ$",
             exponent: 2,
             iso_alpha_code: "NZD",
             iso_numeric_code: "554",
             locale: EnUs,
+            minor_unit_name: "Cent",
             minor_units: 10,
             name: "New Zealand Dollar",
+            plural_name: "New Zealand Dollars",
             symbol: "$",
             symbol_first: true,
         },
@@ -1152,6 +1471,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 5,
             name: "Omani Rial",
+            plural_name: "Omani Rials",
             symbol: "ر.ع.",
             symbol_first: true,
         },
@@ -1162,6 +1482,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Panamanian Balboa",
+            plural_name: "Panamanian Balboas",
             symbol: "B/.",
             symbol_first: true,
         },
@@ -1172,16 +1493,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Peruvian Sol",
+            plural_name: "Peruvian Soles",
             symbol: "S/",
             symbol_first: true,
         },
         PGK : {
+            disambiguated_symbol: "PGK",
             exponent: 2,
             iso_alpha_code: "PGK",
             iso_numeric_code: "598",
             locale: EnUs,
             minor_units: 5,
             name: "Papua New Guinean Kina",
+            plural_name: "Papua New Guinean Kinas",
             symbol: "K",
             symbol_first: false,
         },
@@ -1192,16 +1516,20 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Philippine Peso",
+            plural_name: "Philippine Pesos",
             symbol: "₱",
             symbol_first: true,
         },
         PKR : {
+            cash_exponent: 0,
+            disambiguated_symbol: "PKR",
             exponent: 2,
             iso_alpha_code: "PKR",
             iso_numeric_code: "586",
             locale: EnUs,
             minor_units: 100,
             name: "Pakistani Rupee",
+            plural_name: "Pakistani Rupees",
             symbol: "₨",
             symbol_first: true,
         },
@@ -1209,9 +1537,11 @@ pub mod iso {
             exponent: 2,
             iso_alpha_code: "PLN",
             iso_numeric_code: "985",
-            locale: EnBy,
+            locale: PlPl,
+            minor_unit_name: "Grosz",
             minor_units: 1,
             name: "Polish Złoty",
+            plural_name: "Polish Złotys",
             symbol: "zł",
             symbol_first: false,
         },
@@ -1222,6 +1552,7 @@ pub mod iso {
             locale: EnBy,
             minor_units: 5000,
             name: "Paraguayan Guaraní",
+            plural_name: "Paraguayan Guaranís",
             symbol: "₲",
             symbol_first: true,
         },
@@ -1232,6 +1563,7 @@ pub mod iso {
             locale: EnBy,
             minor_units: 1,
             name: "Qatari Riyal",
+            plural_name: "Qatari Riyals",
             symbol: "ر.ق",
             symbol_first: false,
         },
@@ -1242,19 +1574,10 @@ pub mod iso {
             locale: EnEu,
             minor_units: 1,
             name: "Romanian Leu",
+            plural_name: "Romanian Lei",
             symbol: "RON",
             symbol_first: false,
         },
-        ROL : {
-            exponent: 0,
-            iso_alpha_code: "ROL",
-            iso_numeric_code: "642",
-            locale: EnEu,
-            minor_units: 1,
-            name: "Romanian Leu",
-            symbol: "ROL",
-            symbol_first: false,
-        },
         RSD : {
             exponent: 2,
             iso_alpha_code: "RSD",
@@ -1262,6 +1585,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Serbian Dinar",
+            plural_name: "Serbian Dinars",
             symbol: "РСД",
             symbol_first: true,
         },
@@ -1269,9 +1593,11 @@ pub mod iso {
             exponent: 2,
             iso_alpha_code: "RUB",
             iso_numeric_code: "643",
-            locale: EnEu,
+            locale: RuRu,
+            minor_unit_name: "Kopek",
             minor_units: 1,
             name: "Russian Ruble",
+            plural_name: "Russian Rubles",
             symbol: "₽",
             symbol_first: false,
         },
@@ -1282,6 +1608,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Rwandan Franc",
+            plural_name: "Rwandan Francs",
             symbol: "FRw",
             symbol_first: false,
         },
@@ -1289,159 +1616,170 @@ pub mod iso {
             exponent: 2,
             iso_alpha_code: "SAR",
             iso_numeric_code: "682",
-            locale: EnUs,
+            locale: ArSa,
+            minor_unit_name: "Halala",
             minor_units: 5,
             name: "Saudi Riyal",
+            plural_name: "Saudi Riyals",
             symbol: "ر.س",
-            symbol_first: true,
+            symbol_first: false,
         },
         SBD : {
+            disambiguated_symbol: "SB$",
             exponent: 2,
             iso_alpha_code: "SBD",
             iso_numeric_code: "090",
             locale: EnUs,
             minor_units: 10,
             name: "Solomon Islands Dollar",
+            plural_name: "Solomon Islands Dollars",
             symbol: "$",
             symbol_first: false,
         },
         SCR : {
+            disambiguated_symbol: "SCR",
             exponent: 2,
             iso_alpha_code: "SCR",
             iso_numeric_code: "690",
             locale: EnUs,
             minor_units: 1,
             name: "Seychellois Rupee",
+            plural_name: "Seychellois Rupees",
             symbol: "₨",
             symbol_first: false,
         },
         SDG : {
+            disambiguated_symbol: "SD£",
             exponent: 2,
             iso_alpha_code: "SDG",
             iso_numeric_code: "938",
             locale: EnUs,
             minor_units: 1,
             name: "Sudanese Pound",
+            plural_name: "Sudanese Pounds",
             symbol: "£",
             symbol_first: true,
         },
         SEK : {
+            cash_exponent: 0,
+            disambiguated_symbol: "SEK",
             exponent: 2,
             iso_alpha_code: "SEK",
             iso_numeric_code: "752",
-            locale: EnBy,
+            locale: SvSe,
+            minor_unit_name: "Öre",
             minor_units: 100,
             name: "Swedish Krona",
+            plural_name: "Swedish Kronor",
             symbol: "kr",
             symbol_first: false,
         },
         SGD : {
+            disambiguated_symbol: "SG$",
             exponent: 2,
             iso_alpha_code: "SGD",
             iso_numeric_code: "702",
             locale: EnUs,
+            minor_unit_name: "Cent",
             minor_units: 1,
             name: "Singapore Dollar",
+            plural_name: "Singapore Dollars",
             symbol: "$",
             symbol_first: true,
         },
         SHP : {
+            disambiguated_symbol: "SH£",
             exponent: 2,
             iso_alpha_code: "SHP",
             iso_numeric_code: "654",
             locale: EnUs,
             minor_units: 1,
             name: "Saint Helenian Pound",
+            plural_name: "Saint Helenian Pounds",
             symbol: "£",
             symbol_first: false,
         },
-        SKK : {
-            exponent: 2,
-            iso_alpha_code: "SKK",
-            iso_numeric_code: "703",
-            locale: EnUs,
-            minor_units: 50,
-            name: "Slovak Koruna",
-            symbol: "Sk",
-            symbol_first: true,
-        },
         SLE : {
+            disambiguated_symbol: "SLE",
             exponent: 2,
             iso_alpha_code: "SLE",
             iso_numeric_code: "925",
             locale: EnUs,
             minor_units: 1000,
             name: "Sierra Leonean Leone",
+            plural_name: "Sierra Leonean Leones",
             symbol: "Le",
             symbol_first: false,
         },
         SLL : {
+            disambiguated_symbol: "SLL",
             exponent: 2,
             iso_alpha_code: "SLL",
             iso_numeric_code: "694",
             locale: EnUs,
             minor_units: 1000,
             name: "Sierra Leonean Leone",
+            plural_name: "Sierra Leonean Leones",
             symbol: "Le",
             symbol_first: false,
         },
         SOS : {
+            disambiguated_symbol: "SOS",
             exponent: 2,
             iso_alpha_code: "SOS",
             iso_numeric_code: "706",
             locale: EnUs,
             minor_units: 1,
             name: "Somali Shilling",
+            plural_name: "Somali Shillings",
             symbol: "Sh",
             symbol_first: false,
         },
         SRD : {
+            disambiguated_symbol: "SR$",
             exponent: 2,
             iso_alpha_code: "SRD",
             iso_numeric_code: "968",
             locale: EnUs,
             minor_units: 1,
             name: "Surinamese Dollar",
+            plural_name: "Surinamese Dollars",
             symbol: "$",
             symbol_first: false,
         },
         SSP : {
+            disambiguated_symbol: "SS£",
             exponent: 2,
             iso_alpha_code: "SSP",
             iso_numeric_code: "728",
             locale: EnUs,
             minor_units: 5,
             name: "South Sudanese Pound",
+            plural_name: "South Sudanese Pounds",
             symbol: "£",
             symbol_first: false,
         },
-        STD : {
-            exponent: 2,
-            iso_alpha_code: "STD",
-            iso_numeric_code: "678",
-            locale: EnUs,
-            minor_units: 10000,
-            name: "São Tomé and Príncipe Dobra",
-            symbol: "Db",
-            symbol_first: false,
-        },
         STN : {
+            disambiguated_symbol: "STN",
             exponent: 2,
             iso_alpha_code: "STN",
             iso_numeric_code: "930",
             locale: EnUs,
             minor_units: 10,
             name: "São Tomé and Príncipe Dobra",
+            plural_name: "São Tomé and Príncipe Dobras",
             symbol: "Db",
             symbol_first: false,
         },
         SVC : {
+            disambiguated_symbol: "SVC",
             exponent: 2,
             iso_alpha_code: "SVC",
             iso_numeric_code: "222",
             locale: EnUs,
             minor_units: 1,
             name: "Salvadoran Colón",
+            plural_name: "Salvadoran Colóns",
             symbol: "₡",
             symbol_first: true,
         },
@@ -1452,6 +1790,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Syrian Pound",
+            plural_name: "Syrian Pounds",
             symbol: "£S",
             symbol_first: false,
         },
@@ -1462,6 +1801,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Swazi Lilangeni",
+            plural_name: "Swazi Emalangeni",
             symbol: "E",
             symbol_first: true,
         },
@@ -1472,6 +1812,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Thai Baht",
+            plural_name: "Thai Baht",
             symbol: "฿",
             symbol_first: true,
         },
@@ -1482,16 +1823,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Tajikistani Somoni",
+            plural_name: "Tajikistani Somonis",
             symbol: "ЅМ",
             symbol_first: false,
         },
         TMT : {
+            disambiguated_symbol: "TMT",
             exponent: 2,
             iso_alpha_code: "TMT",
             iso_numeric_code: "934",
             locale: EnUs,
             minor_units: 1,
             name: "Turkmenistani Manat",
+            plural_name: "Turkmenistani Manats",
             symbol: "T",
             symbol_first: false,
         },
@@ -1502,6 +1846,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 10,
             name: "Tunisian Dinar",
+            plural_name: "Tunisian Dinars",
             symbol: "د.ت",
             symbol_first: false,
         },
@@ -1512,6 +1857,7 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Tongan Paʻanga",
+            plural_name: "Tongan Paʻanga",
             symbol: "T$",
             symbol_first: true,
         },
@@ -1520,38 +1866,48 @@ pub mod iso {
             iso_alpha_code: "TRY",
             iso_numeric_code: "949",
             locale: EnEu,
+            minor_unit_name: "Kuruş",
             minor_units: 1,
             name: "Turkish Lira",
+            plural_name: "Turkish Liras",
             symbol: "₺",
             symbol_first: true,
         },
         TTD : {
+            disambiguated_symbol: "TT$",
             exponent: 2,
             iso_alpha_code: "TTD",
             iso_numeric_code: "780",
             locale: EnUs,
             minor_units: 1,
             name: "Trinidad and Tobago Dollar",
+            plural_name: "Trinidad and Tobago Dollars",
             symbol: "$",
             symbol_first: false,
         },
         TWD : {
+            cash_exponent: 0,
+            disambiguated_symbol: "NT$",
             exponent: 2,
             iso_alpha_code: "TWD",
             iso_numeric_code: "901",
             locale: EnUs,
             minor_units: 50,
             name: "New Taiwan Dollar",
+            plural_name: "New Taiwan Dollars",
             symbol: "$",
             symbol_first: true,
         },
         TZS : {
+            cash_exponent: 0,
+            disambiguated_symbol: "TZS",
             exponent: 2,
             iso_alpha_code: "TZS",
             iso_numeric_code: "834",
             locale: EnUs,
             minor_units: 5000,
             name: "Tanzanian Shilling",
+            plural_name: "Tanzanian Shillings",
             symbol: "Sh",
             symbol_first: true,
         },
@@ -1560,8 +1916,10 @@ pub mod iso {
             iso_alpha_code: "UAH",
             iso_numeric_code: "980",
             locale: EnUs,
+            minor_unit_name: "Kopiyka",
             minor_units: 1,
             name: "Ukrainian Hryvnia",
+            plural_name: "Ukrainian Hryvnias",
             symbol: "₴",
             symbol_first: false,
         },
@@ -1572,19 +1930,47 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1000,
             name: "Ugandan Shilling",
+            plural_name: "Ugandan Shillings",
             symbol: "USh",
             symbol_first: false,
         },
         USD : {
+            disambiguated_symbol: "US$",
             exponent: 2,
             iso_alpha_code: "USD",
             iso_numeric_code: "840",
             locale: EnUs,
+            minor_unit_name: "Cent",
             minor_units: 1,
             name: "United States Dollar",
+            plural_name: "United States Dollars",
             symbol: "$",
             symbol_first: true,
         },
+        USN : {
+            exponent: 2,
+            iso_alpha_code: "USN",
+            iso_numeric_code: "997",
+            kind: CurrencyKind::Fund,
+            locale: EnUs,
+            minor_units: 100,
+            name: "US Dollar (Next day)",
+            plural_name: "US Dollars (Next day)",
+            symbol: "USN",
+            symbol_first: false,
+        },
+        UYI : {
+            exponent: 0,
+            iso_alpha_code: "UYI",
+            iso_numeric_code: "940",
+            kind: CurrencyKind::Fund,
+            locale: EnUs,
+            minor_units: 1,
+            name: "Uruguay Peso en Unidades Indexadas",
+            plural_name: "Uruguay Pesos en Unidades Indexadas",
+            symbol: "UYI",
+            symbol_first: false,
+        },
         UYU : {
             exponent: 2,
             iso_alpha_code: "UYU",
@@ -1592,6 +1978,7 @@ pub mod iso {
             locale: EnEu,
             minor_units: 100,
             name: "Uruguayan Peso",
+            plural_name: "Uruguayan Pesos",
             symbol: "$U",
             symbol_first: true,
         },
@@ -1599,22 +1986,38 @@ pub mod iso {
             exponent: 4,
             iso_alpha_code: "UYW",
             iso_numeric_code: "927",
+            kind: CurrencyKind::Fund,
             locale: EnEu,
             minor_units: 1000,
             name: "Unidad Previsional",
+            plural_name: "Unidades Previsionales",
             symbol: "UP",
             symbol_first: true,
         },
         UZS : {
+            cash_exponent: 0,
             exponent: 2,
             iso_alpha_code: "UZS",
             iso_numeric_code: "860",
             locale: EnUs,
             minor_units: 100,
             name: "Uzbekistan Som",
+            plural_name: "Uzbekistan Soms",
             symbol: "so'm",
             symbol_first: false,
         },
+        VED : {
+            disambiguated_symbol: "VED",
+            exponent: 2,
+            iso_alpha_code: "VED",
+            iso_numeric_code: "926",
+            locale: EnEu,
+            minor_units: 1,
+            name: "Venezuelan Digital Bolívar",
+            plural_name: "Venezuelan Digital Bolívares",
+            symbol: "Bs.D",
+            symbol_first: true,
+        },
         VES : {
             exponent: 2,
             iso_alpha_code: "VES",
@@ -1622,6 +2025,7 @@ pub mod iso {
             locale: EnEu,
             minor_units: 1,
             name: "Venezuelan Bolívar Soberano",
+            plural_name: "Venezuelan Bolívares Soberanos",
             symbol: "Bs",
             symbol_first: true,
         },
@@ -1632,6 +2036,7 @@ pub mod iso {
             locale: EnEu,
             minor_units: 100,
             name: "Vietnamese Đồng",
+            plural_name: "Vietnamese Đồng",
             symbol: "₫",
             symbol_first: false,
         },
@@ -1642,16 +2047,19 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Vanuatu Vatu",
+            plural_name: "Vanuatu Vatus",
             symbol: "Vt",
             symbol_first: true,
         },
         WST : {
+            disambiguated_symbol: "WST",
             exponent: 2,
             iso_alpha_code: "WST",
             iso_numeric_code: "882",
             locale: EnUs,
             minor_units: 10,
             name: "Samoan Tala",
+            plural_name: "Samoan Talas",
             symbol: "T",
             symbol_first: false,
         },
@@ -1662,76 +2070,97 @@ pub mod iso {
             locale: EnUs,
             minor_units: 100,
             name: "Central African Cfa Franc",
+            plural_name: "Central African Cfa Francs",
             symbol: "CFA",
             symbol_first: false,
         },
         XAG : {
+            disambiguated_symbol: "XAG",
             exponent: 0,
             iso_alpha_code: "XAG",
             iso_numeric_code: "961",
+            kind: CurrencyKind::Metal,
             locale: EnUs,
             minor_units: 100,
             name: "Silver (Troy Ounce)",
+            plural_name: "Silver (Troy Ounce)",
             symbol: "oz t",
             symbol_first: false,
         },
         XAU : {
+            disambiguated_symbol: "XAU",
             exponent: 0,
             iso_alpha_code: "XAU",
             iso_numeric_code: "959",
+            kind: CurrencyKind::Metal,
             locale: EnUs,
             minor_units: 100,
             name: "Gold (Troy Ounce)",
+            plural_name: "Gold (Troy Ounce)",
             symbol: "oz t",
             symbol_first: false,
         },
         XBA : {
+            disambiguated_symbol: "XBA",
             exponent: 0,
             iso_alpha_code: "XBA",
             iso_numeric_code: "955",
+            kind: CurrencyKind::Special,
             locale: EnUs,
             minor_units: 100,
             name: "European Composite Unit",
+            plural_name: "European Composite Units",
             symbol: "",
             symbol_first: false,
         },
         XBB : {
+            disambiguated_symbol: "XBB",
             exponent: 0,
             iso_alpha_code: "XBB",
             iso_numeric_code: "956",
+            kind: CurrencyKind::Special,
             locale: EnUs,
             minor_units: 100,
             name: "European Monetary Unit",
+            plural_name: "European Monetary Units",
             symbol: "",
             symbol_first: false,
         },
         XBC : {
+            disambiguated_symbol: "XBC",
             exponent: 0,
             iso_alpha_code: "XBC",
             iso_numeric_code: "957",
+            kind: CurrencyKind::Special,
             locale: EnUs,
             minor_units: 100,
             name: "European Unit of Account 9",
+            plural_name: "European Unit of Account 9",
             symbol: "",
             symbol_first: false,
         },
         XBD : {
+            disambiguated_symbol: "XBD",
             exponent: 0,
             iso_alpha_code: "XBD",
             iso_numeric_code: "958",
+            kind: CurrencyKind::Special,
             locale: EnUs,
             minor_units: 100,
             name: "European Unit of Account 17",
+            plural_name: "European Unit of Account 17",
             symbol: "",
             symbol_first: false,
         },
         XCD : {
+            disambiguated_symbol: "EC$",
             exponent: 2,
             iso_alpha_code: "XCD",
             iso_numeric_code: "951",
             locale: EnUs,
             minor_units: 1,
             name: "East Caribbean Dollar",
+            plural_name: "East Caribbean Dollars",
             symbol: "$",
             symbol_first: true,
         },
@@ -1739,69 +2168,125 @@ pub mod iso {
             exponent: 0,
             iso_alpha_code: "XDR",
             iso_numeric_code: "960",
+            kind: CurrencyKind::Special,
             locale: EnUs,
             minor_units: 100,
             name: "Special Drawing Rights",
+            plural_name: "Special Drawing Rights",
             symbol: "SDR",
             symbol_first: false,
         },
         XOF : {
+            disambiguated_symbol: "XOF",
             exponent: 0,
             iso_alpha_code: "XOF",
             iso_numeric_code: "952",
             locale: EnUs,
             minor_units: 100,
             name: "West African Cfa Franc",
+            plural_name: "West African Cfa Francs",
             symbol: "Fr",
             symbol_first: false,
         },
         XPD : {
+            disambiguated_symbol: "XPD",
             exponent: 0,
             iso_alpha_code: "XPD",
             iso_numeric_code: "964",
+            kind: CurrencyKind::Metal,
             locale: EnUs,
             minor_units: 100,
             name: "Palladium",
+            plural_name: "Palladium",
             symbol: "oz t",
             symbol_first: false,
         },
         XPF : {
+            disambiguated_symbol: "XPF",
             exponent: 0,
             iso_alpha_code: "XPF",
             iso_numeric_code: "953",
             locale: EnUs,
             minor_units: 100,
             name: "Cfp Franc",
+            plural_name: "Cfp Francs",
             symbol: "Fr",
             symbol_first: false,
         },
         XPT : {
+            disambiguated_symbol: "XPT",
             exponent: 0,
             iso_alpha_code: "XPT",
             iso_numeric_code: "962",
+            kind: CurrencyKind::Metal,
             locale: EnUs,
             minor_units: 100,
             name: "Platinum",
+            plural_name: "Platinum",
             symbol: "oz t",
             symbol_first: false,
         },
+        XSU : {
+            disambiguated_symbol: "XSU",
+            exponent: 0,
+            iso_alpha_code: "XSU",
+            iso_numeric_code: "994",
+            kind: CurrencyKind::Special,
+            locale: EnUs,
+            minor_units: 1,
+            name: "Sucre",
+            plural_name: "Sucres",
+            symbol: "",
+            symbol_first: false,
+        },
         XTS : {
+            disambiguated_symbol: "XTS",
             exponent: 0,
             iso_alpha_code: "XTS",
             iso_numeric_code: "963",
+            kind: CurrencyKind::Special,
             locale: EnUs,
             minor_units: 100,
             name: "Codes specifically reserved for testing purposes",
-            symbol: "oz t",
+            plural_name: "Codes specifically reserved for testing purposes",
+            symbol: "",
+            symbol_first: false,
+        },
+        XUA : {
+            disambiguated_symbol: "XUA",
+            exponent: 0,
+            iso_alpha_code: "XUA",
+            iso_numeric_code: "965",
+            kind: CurrencyKind::Special,
+            locale: EnUs,
+            minor_units: 1,
+            name: "ADB Unit of Account",
+            plural_name: "ADB Units of Account",
+            symbol: "",
+            symbol_first: false,
+        },
+        XXX : {
+            disambiguated_symbol: "XXX",
+            exponent: 0,
+            iso_alpha_code: "XXX",
+            iso_numeric_code: "999",
+            kind: CurrencyKind::Special,
+            locale: EnUs,
+            minor_units: 1,
+            name: "No currency",
+            plural_name: "No currency",
+            symbol: "",
             symbol_first: false,
         },
         YER : {
+            disambiguated_symbol: "YER",
             exponent: 2,
             iso_alpha_code: "YER",
             iso_numeric_code: "886",
             locale: EnUs,
             minor_units: 100,
             name: "Yemeni Rial",
+            plural_name: "Yemeni Rials",
             symbol: "﷼",
             symbol_first: false,
         },
@@ -1810,28 +2295,22 @@ pub mod iso {
             iso_alpha_code: "ZAR",
             iso_numeric_code: "710",
             locale: EnUs,
+            minor_unit_name: "Cent",
             minor_units: 10,
             name: "South African Rand",
+            plural_name: "South African Rand",
             symbol: "R",
             symbol_first: true,
         },
-        ZMK : {
-            exponent: 2,
-            iso_alpha_code: "ZMK",
-            iso_numeric_code: "894",
-            locale: EnUs,
-            minor_units: 5,
-            name: "Zambian Kwacha",
-            symbol: "ZK",
-            symbol_first: false,
-        },
         ZMW : {
+            disambiguated_symbol: "ZMW",
             exponent: 2,
             iso_alpha_code: "ZMW",
             iso_numeric_code: "967",
             locale: EnUs,
             minor_units: 5,
             name: "Zambian Kwacha",
+            plural_name: "Zambian Kwachas",
             symbol: "K",
             symbol_first: true,
         },
@@ -1842,15 +2321,329 @@ pub mod iso {
             locale: EnUs,
             minor_units: 1,
             name: "Zimbabwe Dollar",
+            plural_name: "Zimbabwe Dollars",
             symbol: "Z$",
             symbol_first: true,
         }
     );
+
+    /// Iterates over the ISO-4217 currencies, in alphabetical order of their codes.
+    pub fn iter() -> impl Iterator<Item = &'static Currency> {
+        CURRENCIES.iter().copied()
+    }
+
+    /// Returns every currency with a symbol, in alphabetical order of their codes (e.g. AUD, CAD, USD... for $).
+    pub fn find_by_symbol(symbol: &str) -> &'static [&'static Currency] {
+        crate::__find_all(BY_SYMBOL, symbol, |currency| currency.symbol)
+    }
+
+    /// Returns the currencies whose code, name or plural name resembles `query`, best matches first.
+    pub fn search(query: &str) -> Vec<&'static Currency> {
+        crate::__search(CURRENCIES, query)
+    }
+
+    /// Returns the currencies of a kind, in alphabetical order of their codes (e.g. the regular currencies used
+    /// for pricing).
+    pub fn with_kind(kind: CurrencyKind) -> impl Iterator<Item = &'static Currency> {
+        iter().filter(move |currency| currency.kind == kind)
+    }
+
+    pub fn with_exponent(exponent: u32) -> impl Iterator<Item = &'static Currency> {
+        iter().filter(move |currency| currency.exponent == exponent)
+    }
+
+    pub fn with_locale(locale: Locale) -> impl Iterator<Item = &'static Currency> {
+        iter().filter(move |currency| currency.locale == locale)
+    }
+
+    pub fn with_symbol_first(symbol_first: bool) -> impl Iterator<Item = &'static Currency> {
+        iter().filter(move |currency| currency.symbol_first == symbol_first)
+    }
+
+    /// Returns the main currency of an ISO 3166 alpha-2 country code (e.g. EUR for DE), ignoring case.
+    pub fn for_country(country: &str) -> Option<&'static Currency> {
+        COUNTRIES
+            .binary_search_by(|(code, _)| crate::currency::cmp_ignore_case(code, country))
+            .ok()
+            .map(|index| COUNTRIES[index].1[0])
+    }
+
+    /// Returns the main currency of the region of a Locale (e.g. CHF for `Locale::DeCh`).
+    ///
+    /// `Locale::EnEu` maps to EUR. Custom locales use the region of their name, if it has one.
+    pub fn for_locale(locale: Locale) -> Option<&'static Currency> {
+        let name = crate::LocalFormat::from_locale(locale).name;
+        match name.split('-').nth(1)? {
+            "EU" => Some(EUR),
+            region => for_country(region),
+        }
+    }
+
+    /// Returns the ISO 3166 alpha-2 codes of the countries which use a currency, in alphabetical order.
+    ///
+    /// Countries where the currency circulates next to their main one are included (e.g. PA for USD).
+    pub fn countries(currency: &Currency) -> impl Iterator<Item = &'static str> + '_ {
+        COUNTRIES
+            .iter()
+            .filter(move |(_, currencies)| currencies.contains(&currency))
+            .map(|(country, _)| *country)
+    }
+
+    /// The currencies used by each country according to ISO 4217, sorted by ISO 3166 code, main currency first.
+    const COUNTRIES: &[(&str, &[&Currency])] = &[
+        ("AD", &[EUR]),
+        ("AE", &[AED]),
+        ("AF", &[AFN]),
+        ("AG", &[XCD]),
+        ("AI", &[XCD]),
+        ("AL", &[ALL]),
+        ("AM", &[AMD]),
+        ("AO", &[AOA]),
+        ("AR", &[ARS]),
+        ("AS", &[USD]),
+        ("AT", &[EUR]),
+        ("AU", &[AUD]),
+        ("AW", &[AWG]),
+        ("AX", &[EUR]),
+        ("AZ", &[AZN]),
+        ("BA", &[BAM]),
+        ("BB", &[BBD]),
+        ("BD", &[BDT]),
+        ("BE", &[EUR]),
+        ("BF", &[XOF]),
+        ("BG", &[BGN]),
+        ("BH", &[BHD]),
+        ("BI", &[BIF]),
+        ("BJ", &[XOF]),
+        ("BL", &[EUR]),
+        ("BM", &[BMD]),
+        ("BN", &[BND]),
+        ("BO", &[BOB, BOV]),
+        ("BQ", &[USD]),
+        ("BR", &[BRL]),
+        ("BS", &[BSD]),
+        ("BT", &[BTN, INR]),
+        ("BV", &[NOK]),
+        ("BW", &[BWP]),
+        ("BY", &[BYN]),
+        ("BZ", &[BZD]),
+        ("CA", &[CAD]),
+        ("CC", &[AUD]),
+        ("CD", &[CDF]),
+        ("CF", &[XAF]),
+        ("CG", &[XAF]),
+        ("CH", &[CHF, CHE, CHW]),
+        ("CI", &[XOF]),
+        ("CK", &[NZD]),
+        ("CL", &[CLP, CLF]),
+        ("CM", &[XAF]),
+        ("CN", &[CNY]),
+        ("CO", &[COP, COU]),
+        ("CR", &[CRC]),
+        ("CU", &[CUP, CUC]),
+        ("CV", &[CVE]),
+        ("CW", &[ANG]),
+        ("CX", &[AUD]),
+        ("CY", &[EUR]),
+        ("CZ", &[CZK]),
+        ("DE", &[EUR]),
+        ("DJ", &[DJF]),
+        ("DK", &[DKK]),
+        ("DM", &[XCD]),
+        ("DO", &[DOP]),
+        ("DZ", &[DZD]),
+        ("EC", &[USD]),
+        ("EE", &[EUR]),
+        ("EG", &[EGP]),
+        ("EH", &[MAD]),
+        ("ER", &[ERN]),
+        ("ES", &[EUR]),
+        ("ET", &[ETB]),
+        ("FI", &[EUR]),
+        ("FJ", &[FJD]),
+        ("FK", &[FKP]),
+        ("FM", &[USD]),
+        ("FO", &[DKK]),
+        ("FR", &[EUR]),
+        ("GA", &[XAF]),
+        ("GB", &[GBP]),
+        ("GD", &[XCD]),
+        ("GE", &[GEL]),
+        ("GF", &[EUR]),
+        ("GG", &[GBP]),
+        ("GH", &[GHS]),
+        ("GI", &[GIP]),
+        ("GL", &[DKK]),
+        ("GM", &[GMD]),
+        ("GN", &[GNF]),
+        ("GP", &[EUR]),
+        ("GQ", &[XAF]),
+        ("GR", &[EUR]),
+        ("GT", &[GTQ]),
+        ("GU", &[USD]),
+        ("GW", &[XOF]),
+        ("GY", &[GYD]),
+        ("HK", &[HKD]),
+        ("HM", &[AUD]),
+        ("HN", &[HNL]),
+        ("HR", &[EUR]),
+        ("HT", &[HTG, USD]),
+        ("HU", &[HUF]),
+        ("ID", &[IDR]),
+        ("IE", &[EUR]),
+        ("IL", &[ILS]),
+        ("IM", &[GBP]),
+        ("IN", &[INR]),
+        ("IO", &[USD]),
+        ("IQ", &[IQD]),
+        ("IR", &[IRR]),
+        ("IS", &[ISK]),
+        ("IT", &[EUR]),
+        ("JE", &[GBP]),
+        ("JM", &[JMD]),
+        ("JO", &[JOD]),
+        ("JP", &[JPY]),
+        ("KE", &[KES]),
+        ("KG", &[KGS]),
+        ("KH", &[KHR]),
+        ("KI", &[AUD]),
+        ("KM", &[KMF]),
+        ("KN", &[XCD]),
+        ("KP", &[KPW]),
+        ("KR", &[KRW]),
+        ("KW", &[KWD]),
+        ("KY", &[KYD]),
+        ("KZ", &[KZT]),
+        ("LA", &[LAK]),
+        ("LB", &[LBP]),
+        ("LC", &[XCD]),
+        ("LI", &[CHF]),
+        ("LK", &[LKR]),
+        ("LR", &[LRD]),
+        ("LS", &[LSL, ZAR]),
+        ("LT", &[EUR]),
+        ("LU", &[EUR]),
+        ("LV", &[EUR]),
+        ("LY", &[LYD]),
+        ("MA", &[MAD]),
+        ("MC", &[EUR]),
+        ("MD", &[MDL]),
+        ("ME", &[EUR]),
+        ("MF", &[EUR]),
+        ("MG", &[MGA]),
+        ("MH", &[USD]),
+        ("MK", &[MKD]),
+        ("ML", &[XOF]),
+        ("MM", &[MMK]),
+        ("MN", &[MNT]),
+        ("MO", &[MOP]),
+        ("MP", &[USD]),
+        ("MQ", &[EUR]),
+        ("MR", &[MRU]),
+        ("MS", &[XCD]),
+        ("MT", &[EUR]),
+        ("MU", &[MUR]),
+        ("MV", &[MVR]),
+        ("MW", &[MWK]),
+        ("MX", &[MXN, MXV]),
+        ("MY", &[MYR]),
+        ("MZ", &[MZN]),
+        ("NA", &[NAD, ZAR]),
+        ("NC", &[XPF]),
+        ("NE", &[XOF]),
+        ("NF", &[AUD]),
+        ("NG", &[NGN]),
+        ("NI", &[NIO]),
+        ("NL", &[EUR]),
+        ("NO", &[NOK]),
+        ("NP", &[NPR]),
+        ("NR", &[AUD]),
+        ("NU", &[NZD]),
+        ("NZ", &[NZD]),
+        ("OM", &[OMR]),
+        ("PA", &[PAB, USD]),
+        ("PE", &[PEN]),
+        ("PF", &[XPF]),
+        ("PG", &[PGK]),
+        ("PH", &[PHP]),
+        ("PK", &[PKR]),
+        ("PL", &[PLN]),
+        ("PM", &[EUR]),
+        ("PN", &[NZD]),
+        ("PR", &[USD]),
+        ("PT", &[EUR]),
+        ("PW", &[USD]),
+        ("PY", &[PYG]),
+        ("QA", &[QAR]),
+        ("RE", &[EUR]),
+        ("RO", &[RON]),
+        ("RS", &[RSD]),
+        ("RU", &[RUB]),
+        ("RW", &[RWF]),
+        ("SA", &[SAR]),
+        ("SB", &[SBD]),
+        ("SC", &[SCR]),
+        ("SD", &[SDG]),
+        ("SE", &[SEK]),
+        ("SG", &[SGD]),
+        ("SH", &[SHP]),
+        ("SI", &[EUR]),
+        ("SJ", &[NOK]),
+        ("SK", &[EUR]),
+        ("SL", &[SLE, SLL]),
+        ("SM", &[EUR]),
+        ("SN", &[XOF]),
+        ("SO", &[SOS]),
+        ("SR", &[SRD]),
+        ("SS", &[SSP]),
+        ("ST", &[STN]),
+        ("SV", &[SVC, USD]),
+        ("SX", &[ANG]),
+        ("SY", &[SYP]),
+        ("SZ", &[SZL]),
+        ("TC", &[USD]),
+        ("TD", &[XAF]),
+        ("TF", &[EUR]),
+        ("TG", &[XOF]),
+        ("TH", &[THB]),
+        ("TJ", &[TJS]),
+        ("TK", &[NZD]),
+        ("TL", &[USD]),
+        ("TM", &[TMT]),
+        ("TN", &[TND]),
+        ("TO", &[TOP]),
+        ("TR", &[TRY]),
+        ("TT", &[TTD]),
+        ("TV", &[AUD]),
+        ("TW", &[TWD]),
+        ("TZ", &[TZS]),
+        ("UA", &[UAH]),
+        ("UG", &[UGX]),
+        ("UM", &[USD]),
+        ("US", &[USD, USN]),
+        ("UY", &[UYU, UYI, UYW]),
+        ("UZ", &[UZS]),
+        ("VA", &[EUR]),
+        ("VC", &[XCD]),
+        ("VE", &[VES, VED]),
+        ("VG", &[USD]),
+        ("VI", &[USD]),
+        ("VN", &[VND]),
+        ("VU", &[VUV]),
+        ("WF", &[XPF]),
+        ("WS", &[WST]),
+        ("YE", &[YER]),
+        ("YT", &[EUR]),
+        ("ZA", &[ZAR]),
+        ("ZM", &[ZMW]),
+        ("ZW", &[ZWL]),
+    ];
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{Direction, FormattableCurrency, Formatter, Locale::*, Money, Params, SymbolStyle};
 
     #[test]
     fn find_returns_known_currencies() {
@@ -1863,6 +2656,201 @@ mod tests {
         assert_eq!(currency_by_alpha, currency_by_numeric);
     }
 
+    #[test]
+    fn all_lists_every_currency_by_code() {
+        assert!(iso::CURRENCIES
+            .windows(2)
+            .all(|pair| pair[0].iso_alpha_code < pair[1].iso_alpha_code));
+        assert!(iso::CURRENCIES
+            .iter()
+            .all(|currency| iso::find(currency.iso_alpha_code) == Some(currency)));
+        assert_eq!(iso::CURRENCIES.first(), Some(&iso::AED));
+        assert_eq!(iso::iter().count(), iso::CURRENCIES.len());
+
+        assert!(iso::with_exponent(0).any(|currency| currency == iso::JPY));
+        assert!(iso::with_exponent(3).all(|currency| currency.exponent == 3));
+        assert_eq!(
+            iso::with_locale(DeCh).collect::<Vec<_>>(),
+            [iso::CHE, iso::CHF, iso::CHW]
+        );
+        assert!(iso::with_symbol_first(false).any(|currency| currency == iso::SEK));
+        assert!(!iso::with_symbol_first(true).any(|currency| currency == iso::SEK));
+    }
+
+    #[test]
+    fn currencies_match_iso_4217_list_one() {
+        let list =
+            crate::currency::iso4217::currencies(include_str!("../../data/iso4217/list-one.xml"));
+        assert_eq!(
+            iso::CURRENCIES
+                .iter()
+                .map(|currency| currency.iso_alpha_code)
+                .collect::<Vec<_>>(),
+            list.iter()
+                .map(|listed| listed.code.as_str())
+                .collect::<Vec<_>>(),
+            "Run `cargo run --example iso4217` to update the table"
+        );
+
+        for (currency, listed) in iso::CURRENCIES.iter().zip(list.iter()) {
+            assert_eq!(
+                currency.iso_numeric_code, listed.numeric_code,
+                "{}",
+                currency
+            );
+            assert_eq!(
+                currency.exponent,
+                listed.minor_units.unwrap_or(0),
+                "{}",
+                currency
+            );
+            assert_eq!(
+                currency.kind.has_minor_unit(),
+                listed.minor_units.is_some(),
+                "{}",
+                currency
+            );
+            if listed.is_fund {
+                assert_eq!(currency.kind, iso::CurrencyKind::Fund, "{}", currency);
+            }
+        }
+    }
+
+    #[test]
+    fn currencies_expose_metadata() {
+        assert_eq!(FormattableCurrency::minor_units(iso::CHF), 5);
+        assert_eq!(iso::USD.numeric_code(), Some("840"));
+        assert_eq!(iso::USD.minor_unit_name(), Some("Cent"));
+        assert_eq!(iso::GBP.minor_unit_name(), Some("Penny"));
+        assert_eq!(iso::JPY.minor_unit_name(), None);
+    }
+
+    #[test]
+    fn currencies_are_classified() {
+        use iso::CurrencyKind::*;
+
+        assert_eq!(iso::USD.kind, Regular);
+        assert!(iso::USD.is_regular());
+        assert_eq!(
+            iso::with_kind(Metal).collect::<Vec<_>>(),
+            [iso::XAG, iso::XAU, iso::XPD, iso::XPT]
+        );
+        assert_eq!(
+            iso::with_kind(Fund)
+                .map(|c| c.iso_alpha_code)
+                .collect::<Vec<_>>(),
+            ["BOV", "CHE", "CHW", "CLF", "COU", "MXV", "USN", "UYI", "UYW"]
+        );
+        assert!(iso::with_kind(Special).any(|currency| currency == iso::XXX));
+        assert!(iso::with_kind(Regular).all(|currency| currency.kind.has_minor_unit()));
+        assert!(!iso::XTS.is_regular());
+        assert_eq!(iso::find_by_num_code("999"), Some(iso::XXX));
+        assert_eq!(iso::countries(iso::USN).collect::<Vec<_>>(), ["US"]);
+        assert_eq!(iso::for_country("US"), Some(iso::USD));
+    }
+
+    #[test]
+    fn currencies_without_minor_units_are_not_rounded() {
+        assert!(!iso::XAU.has_fixed_exponent());
+        assert!(iso::JPY.has_fixed_exponent());
+        assert!(iso::CLF.has_fixed_exponent());
+
+        let gold = Money::from_str("1.2345", iso::XAU).unwrap();
+        assert_eq!(gold.to_string(), "1.2345oz t");
+        assert_eq!(Money::from_major(2, iso::XAU).to_string(), "2oz t");
+        assert_eq!(Money::from_str("1.5", iso::JPY).unwrap().to_string(), "¥2");
+
+        let mut table = crate::Table::new(Params::default(), crate::SymbolAlignment::Left);
+        table.push(gold);
+        table.push(Money::from_str("10.5", iso::XAU).unwrap());
+        assert_eq!(table.to_string(), "oz t  1.2345\noz t 10.5");
+    }
+
+    #[test]
+    fn currencies_have_cash_exponents() {
+        use crate::{CashPrecision, PrecisionProfile, ProcessorPrecision};
+
+        assert_eq!((iso::HUF.exponent, iso::HUF.cash_exponent), (2, 0));
+        assert_eq!((iso::TWD.exponent, iso::TWD.cash_exponent), (2, 0));
+        assert_eq!((iso::CLP.exponent, iso::CLP.cash_exponent), (0, 0));
+        assert_eq!((iso::USD.exponent, iso::USD.cash_exponent), (2, 2));
+
+        // Card processors which take no decimals of forints or Taiwan dollars.
+        let processor = PrecisionProfile::Processor(ProcessorPrecision {
+            exponents: vec![("HUF", 0), ("TWD", 0)].into(),
+            ..Default::default()
+        });
+        let forints = Money::from_str("1234,56", iso::HUF).unwrap();
+        assert_eq!(forints.validate_for(&PrecisionProfile::Iso), Ok(()));
+        assert!(forints.validate_for(&processor).is_err());
+        assert_eq!(
+            forints.round_for(&processor),
+            Money::from_major(1_235, iso::HUF)
+        );
+        assert_eq!(
+            Money::from_major(1_235, iso::HUF).validate_for(&processor),
+            Ok(())
+        );
+
+        let dollars = Money::from_str("1,234.50", iso::TWD).unwrap();
+        assert_eq!(dollars.validate_for(&PrecisionProfile::Iso), Ok(()));
+        assert!(dollars.validate_for(&processor).is_err());
+        assert_eq!(
+            dollars.round_for(&processor),
+            Money::from_major(1_234, iso::TWD)
+        );
+        let cash = PrecisionProfile::Cash(CashPrecision::default());
+        assert_eq!(dollars.round_for(&cash), Money::from_major(1_235, iso::TWD));
+
+        let cash = PrecisionProfile::Cash(CashPrecision {
+            increments: vec![("CHF", rust_decimal_macros::dec!(0.05))].into(),
+            ..Default::default()
+        });
+        let francs = Money::from_minor(1_057, iso::CHF);
+        assert!(francs.validate_for(&cash).is_err());
+        assert_eq!(francs.round_for(&cash), Money::from_minor(1_055, iso::CHF));
+    }
+
+    #[test]
+    fn find_ignores_case() {
+        assert_eq!(iso::find("usd"), Some(iso::USD));
+        assert_eq!(iso::find("Eur"), Some(iso::EUR));
+        assert_eq!(iso::find("us"), None);
+        assert_eq!(iso::find("usdd"), None);
+    }
+
+    #[test]
+    fn find_by_symbol_returns_every_candidate() {
+        assert_eq!(iso::find_by_symbol("€"), [iso::EUR]);
+        let dollars = iso::find_by_symbol("$");
+        assert!(dollars.len() > 10);
+        assert!(
+            dollars.contains(&iso::USD)
+                && dollars.contains(&iso::CAD)
+                && dollars.contains(&iso::AUD)
+        );
+        assert!(dollars
+            .windows(2)
+            .all(|pair| pair[0].iso_alpha_code < pair[1].iso_alpha_code));
+        assert!(dollars.iter().all(|currency| currency.symbol == "$"));
+        assert!(iso::find_by_symbol("US$").is_empty());
+        assert!(iso::find_by_symbol("").is_empty());
+    }
+
+    #[test]
+    fn search_ranks_names() {
+        assert_eq!(iso::search("Euro").first(), Some(&iso::EUR));
+        assert_eq!(iso::search("eur").first(), Some(&iso::EUR));
+        assert_eq!(iso::search("yen").first(), Some(&iso::JPY));
+        assert_eq!(iso::search("Japanese Yen"), [iso::JPY]);
+        assert_eq!(iso::search("swiss franc"), [iso::CHF]);
+        assert!(iso::search("dollar").contains(&iso::USD));
+        assert!(iso::search("British Pund").contains(&iso::GBP));
+        assert!(iso::search("pounds").contains(&iso::GBP));
+        assert!(iso::search("").is_empty());
+        assert!(iso::search("zzzz").is_empty());
+    }
+
     #[test]
     fn find_raises_invalid_currency_error_on_unknown_currency() {
         assert_eq!(iso::find("fake"), None,);
@@ -1877,6 +2865,127 @@ mod tests {
         assert_eq!(iso::USD.symbol, "$");
     }
 
+    #[test]
+    fn currencies_use_their_regional_format() {
+        let format = |amount, currency| format!("{}", Money::from_minor(amount, currency));
+        assert_eq!(format(123_456, iso::BRL), "R$\u{a0}1.234,56");
+        assert_eq!(format(123_456, iso::CHF), "Fr\u{a0}1’234.56");
+        assert_eq!(format(123_456, iso::RUB), "1\u{a0}234,56\u{a0}₽");
+        assert_eq!(format(1_234, iso::JPY), "¥1,234");
+    }
+
+    #[test]
+    fn right_to_left_currencies_are_bidi_safe() {
+        let format = |amount, currency| format!("{}", Money::from_minor(amount, currency));
+        assert_eq!(
+            format(-123_456, iso::AED),
+            "\u{200f}\u{200e}-1,234.56\u{a0}\u{2068}د.إ\u{2069}"
+        );
+        assert_eq!(
+            format(-123_456, iso::SAR),
+            "\u{200f}\u{61c}-١٬٢٣٤٫٥٦\u{a0}\u{2068}ر.س\u{2069}"
+        );
+        assert_eq!(
+            format(123_456, iso::ILS),
+            "\u{200f}1,234.56\u{a0}\u{2068}₪\u{2069}"
+        );
+
+        // Left to right locales only isolate the symbol when asked to.
+        let money = Money::from_minor(-123_456, iso::AED);
+        assert_eq!(money.to_string_with_locale(EnUs), "-د.إ1,234.56");
+        let params = Params {
+            symbol: Some(iso::AED.symbol),
+            bidi: Some(Direction::LeftToRight),
+            ..Default::default()
+        };
+        assert_eq!(
+            Formatter::money(&money, params),
+            "-\u{2068}د.إ\u{2069}1,234.56"
+        );
+
+        // CLDR symbols are isolated too, along with their own marks.
+        #[cfg(feature = "cldr")]
+        assert_eq!(
+            Money::from_minor(-123_456, iso::SAR).to_string_with_locale(ArAe),
+            "\u{200f}\u{200e}-1,234.56\u{a0}\u{2068}ر.س.\u{200f}\u{2069}"
+        );
+    }
+
+    #[test]
+    fn shared_symbols_have_distinct_disambiguated_symbols() {
+        assert_eq!(iso::USD.disambiguated_symbol, "US$");
+        assert_eq!(iso::CAD.disambiguated_symbol, "CA$");
+        assert_eq!(iso::AUD.disambiguated_symbol, "A$");
+        assert_eq!(iso::JPY.disambiguated_symbol, "JP¥");
+        assert_eq!(iso::GBP.disambiguated_symbol, "£");
+        assert_eq!(iso::CHF.disambiguated_symbol, "CHF");
+        assert_eq!(iso::EUR.disambiguated_symbol, "€");
+        assert_eq!(iso::DKK.narrow_symbol, "kr");
+
+        let mut symbols: Vec<&str> = [
+            iso::ARS,
+            iso::AUD,
+            iso::CAD,
+            iso::HKD,
+            iso::MXN,
+            iso::NZD,
+            iso::SGD,
+            iso::TWD,
+            iso::USD,
+        ]
+        .iter()
+        .map(|currency| currency.disambiguated_symbol)
+        .collect();
+        symbols.sort_unstable();
+        symbols.dedup();
+        assert_eq!(symbols.len(), 9);
+    }
+
+    #[test]
+    fn local_symbols_depend_on_the_reader() {
+        assert_eq!(iso::USD.styled_symbol(SymbolStyle::Local(EnUs)), "$");
+        assert_eq!(iso::USD.styled_symbol(SymbolStyle::Local(EnCa)), "US$");
+        assert_eq!(iso::CAD.styled_symbol(SymbolStyle::Local(EnCa)), "$");
+        assert_eq!(iso::CAD.styled_symbol(SymbolStyle::Local(EnUs)), "CA$");
+
+        let usd = Money::from_minor(123_456, iso::USD);
+        assert_eq!(format!("{}", usd), "$1,234.56");
+        assert_eq!(format!("{:#}", usd), "US$1,234.56");
+        assert_eq!(usd.to_string_with_locale(EnCa), "US$1,234.56");
+        assert_eq!(format!("{:#}", usd.format_in(EnUs)), "US$1,234.56");
+    }
+
+    #[test]
+    fn currencies_for_countries_and_locales() {
+        assert_eq!(iso::for_country("DE"), Some(iso::EUR));
+        assert_eq!(iso::for_country("jp"), Some(iso::JPY));
+        assert_eq!(iso::for_country("BT"), Some(iso::BTN));
+        assert_eq!(iso::for_country("XX"), None);
+
+        assert_eq!(iso::for_locale(DeCh), Some(iso::CHF));
+        assert_eq!(iso::for_locale(EnEu), Some(iso::EUR));
+        assert_eq!(iso::for_locale(PtBr), Some(iso::BRL));
+        assert_eq!(iso::for_locale(EnIn), Some(iso::INR));
+    }
+
+    #[test]
+    fn countries_using_a_currency() {
+        let eurozone = [
+            "AT", "BE", "CY", "DE", "EE", "ES", "FI", "FR", "GR", "HR", "IE", "IT", "LT", "LU",
+            "LV", "MT", "NL", "PT", "SI", "SK",
+        ];
+        let countries: Vec<&str> = iso::countries(iso::EUR).collect();
+        for member in eurozone {
+            assert!(countries.contains(&member), "{} is in the eurozone", member);
+        }
+        assert!(countries.contains(&"MC"));
+        assert!(!countries.contains(&"GB"));
+
+        let countries: Vec<&str> = iso::countries(iso::INR).collect();
+        assert_eq!(countries, ["BT", "IN"]);
+        assert_eq!(iso::countries(iso::XAU).count(), 0);
+    }
+
     #[test]
     fn find_and_reference_point_to_same() {
         assert_eq!(iso::USD, iso::find("USD").unwrap());
@@ -6,6 +6,7 @@ pub enum MoneyError {
     InvalidCurrency,
     InvalidAmount,
     InvalidRatio,
+    InvalidLocale,
 }
 
 impl fmt::Display for MoneyError {
@@ -14,6 +15,7 @@ impl fmt::Display for MoneyError {
             MoneyError::InvalidCurrency => write!(f, "Currency was not valid"),
             MoneyError::InvalidAmount => write!(f, "Amount not parsable"),
             MoneyError::InvalidRatio => write!(f, "Ratio was not valid"),
+            MoneyError::InvalidLocale => write!(f, "Locale was not recognized"),
         }
     }
 }
@@ -24,6 +26,7 @@ impl error::Error for MoneyError {
             MoneyError::InvalidCurrency => "Currency was not valid",
             MoneyError::InvalidAmount => "Amount not pauseable",
             MoneyError::InvalidRatio => "Ratio was not valid",
+            MoneyError::InvalidLocale => "Locale was not recognized",
         }
     }
 }
@@ -2,14 +2,18 @@ use crate::currency::FormattableCurrency;
 use crate::{Money, MoneyError};
 use rust_decimal::Decimal;
 use std::collections::HashMap;
+use std::hash::Hash;
 
 /// Stores `ExchangeRate`s for easier access.
+///
+/// Rates are found by the currencies they convert between, so currencies of different sets with the same code
+/// (e.g. in an `Exchange` of `AnyCurrency`) keep their own rates.
 #[derive(Debug, Default)]
 pub struct Exchange<'a, T: FormattableCurrency> {
-    map: HashMap<String, ExchangeRate<'a, T>>,
+    map: HashMap<(T, T), ExchangeRate<'a, T>>,
 }
 
-impl<'a, T: FormattableCurrency> Exchange<'a, T> {
+impl<'a, T: FormattableCurrency + Hash> Exchange<'a, T> {
     pub fn new() -> Exchange<'a, T> {
         Exchange {
             map: HashMap::new(),
@@ -18,18 +22,21 @@ impl<'a, T: FormattableCurrency> Exchange<'a, T> {
 
     /// Update an ExchangeRate or add it if does not exist.
     pub fn set_rate(&mut self, rate: &ExchangeRate<'a, T>) {
-        let key = Exchange::generate_key(rate.from, rate.to);
-        self.map.insert(key, *rate);
+        self.map.insert((*rate.from, *rate.to), *rate);
     }
 
     /// Return the ExchangeRate given the currency pair.
     pub fn get_rate(&self, from: &T, to: &T) -> Option<ExchangeRate<'a, T>> {
-        let key = Exchange::generate_key(from, to);
-        self.map.get(&key).copied()
+        self.map.get(&(*from, *to)).copied()
     }
 
-    fn generate_key(from: &T, to: &T) -> String {
-        from.to_string() + "-" + &to.to_string()
+    /// Converts a Money into another currency, at the stored rate from its currency to `to`.
+    ///
+    /// Returns `InvalidCurrency` if the Exchange has no such rate.
+    pub fn convert(&self, money: &Money<'a, T>, to: &T) -> Result<Money<'a, T>, MoneyError> {
+        self.get_rate(money.currency(), to)
+            .ok_or(MoneyError::InvalidCurrency)?
+            .convert(money)
     }
 }
 
@@ -117,6 +124,19 @@ mod tests {
         assert_eq!(fetched_rate.rate, dec!(1.6));
     }
 
+    #[test]
+    fn exchange_converts_with_stored_rates() {
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&ExchangeRate::new(test::USD, test::EUR, dec!(1.5)).unwrap());
+
+        let euros = exchange.convert(&Money::from_minor(1_000, test::USD), test::EUR);
+        assert_eq!(euros, Ok(Money::from_minor(1_500, test::EUR)));
+        assert_eq!(
+            exchange.convert(&Money::from_minor(1_000, test::USD), test::GBP),
+            Err(MoneyError::InvalidCurrency)
+        );
+    }
+
     #[test]
     fn rate_convert() {
         let rate = ExchangeRate::new(test::USD, test::EUR, dec!(1.5)).unwrap();
@@ -1,6 +1,18 @@
-use crate::currency::FormattableCurrency;
-use crate::{Money, Round};
+use crate::currency::{FormattableCurrency, SymbolStyle};
+use crate::{
+    Direction, LocalFormat, Money, NegativeStyle, NumberingSystem, PluralCategory, PluralRule,
+    Round, Spacing,
+};
+use rust_decimal::Decimal;
+use std::borrow::Cow;
 use std::cmp::Ordering;
+use std::fmt;
+
+const LEFT_TO_RIGHT_MARK: char = '\u{200e}';
+const RIGHT_TO_LEFT_MARK: char = '\u{200f}';
+const ARABIC_LETTER_MARK: char = '\u{61c}';
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
 
 /// Converts Money objects into human readable strings.
 pub struct Formatter;
@@ -8,6 +20,20 @@ pub struct Formatter;
 impl<'a> Formatter {
     /// Returns a formatted Money String given parameters and a Money object.
     pub fn money<T: FormattableCurrency>(money: &Money<'a, T>, params: Params) -> String {
+        let mut result = String::new();
+        Formatter::write_money(&mut result, money, &params)
+            .expect("Writing to a String should never fail");
+        result
+    }
+
+    /// Writes a formatted Money into a `fmt::Write`, given parameters and a Money object.
+    ///
+    /// Performs no heap allocation, so formatting many amounts into a reused buffer stays cheap.
+    pub fn write_money<W: fmt::Write, T: FormattableCurrency>(
+        f: &mut W,
+        money: &Money<'a, T>,
+        params: &Params,
+    ) -> fmt::Result {
         let mut decimal = *money.amount();
 
         // Round the decimal
@@ -15,67 +41,150 @@ impl<'a> Formatter {
             decimal = *money.round(x, Round::HalfEven).amount();
         }
 
-        // Format the Amount String
-        let amount = Formatter::amount(&format!("{}", decimal), &params);
+        // Right to left text starts with a mark, so the amount keeps its direction next to neutral characters.
+        if params.bidi == Some(Direction::RightToLeft) {
+            f.write_char(RIGHT_TO_LEFT_MARK)?;
+        }
+
+        let negative = money.is_negative();
+        if negative && params.negative_style == NegativeStyle::Parentheses {
+            f.write_char('(')?;
+        }
 
         // Position values in the Output String
-        let mut result = String::new();
-        for position in params.positions.iter() {
+        let positions = match &params.negative_positions {
+            Some(positions) if negative => positions,
+            _ => &params.positions,
+        };
+        for position in positions.iter() {
             match position {
-                Position::Space => result.push(' '),
-                Position::Amount => result.push_str(&amount),
-                Position::Code => result.push_str(params.code.unwrap_or("")),
-                Position::Symbol => result.push_str(params.symbol.unwrap_or("")),
-                Position::Sign => result.push_str(if money.is_negative() { "-" } else { "" }),
+                Position::Space => f.write_str(params.spacing.as_str())?,
+                Position::Amount => Formatter::write_amount(f, &decimal, params)?,
+                Position::Code => f.write_str(params.code.unwrap_or(""))?,
+                Position::Symbol => {
+                    let symbol = match params.symbol_style {
+                        Some(style) => money.currency().styled_symbol(style),
+                        None => params.symbol.unwrap_or(""),
+                    };
+                    Formatter::write_isolated(f, symbol, params)?
+                }
+                Position::Sign if negative && params.negative_style == NegativeStyle::Sign => {
+                    Formatter::write_sign(f, params)?
+                }
+                Position::Sign => {}
+                Position::Name => {
+                    Formatter::write_isolated(f, Formatter::name(&decimal, params), params)?
+                }
+                Position::NumericCode => f.write_str(
+                    params
+                        .numeric_code
+                        .or_else(|| money.currency().numeric_code())
+                        .unwrap_or(""),
+                )?,
+                Position::Literal(text) => f.write_str(text)?,
             }
         }
-        result
+
+        match params.negative_style {
+            NegativeStyle::TrailingSign if negative => f.write_char('-'),
+            NegativeStyle::Parentheses if negative => f.write_char(')'),
+            _ => Ok(()),
+        }
     }
 
-    /// Returns a formatted amount String, given the raw amount and formatting parameters.
-    fn amount(raw_amount: &str, params: &Params) -> String {
-        // Split amount into digits and exponent.
-        let amount_split: Vec<&str> = raw_amount.split('.').collect();
-        let mut amount_digits = amount_split[0].to_string();
-
-        // Format the digits
-        amount_digits.retain(|c| c != '-');
-        amount_digits = Formatter::digits(
-            &amount_digits,
-            params.digit_separator,
-            &params.separator_pattern,
-        );
-        let mut result = amount_digits;
-
-        // Format the exponent, and add to digits
-        match amount_split.len().cmp(&2) {
-            Ordering::Equal => {
-                // Exponent found, concatenate to digits.
-                result.push(params.exponent_separator);
-                result += amount_split[1];
-            }
-            Ordering::Less => {
-                // No exponent, do nothing.
-            }
-            Ordering::Greater => panic!("More than 1 exponent separators when parsing Decimal"),
+    /// Writes text whose direction may differ from the amount's, isolated from its surroundings if bidi is on.
+    fn write_isolated<W: fmt::Write>(f: &mut W, text: &str, params: &Params) -> fmt::Result {
+        if params.bidi.is_none() || text.is_empty() {
+            return f.write_str(text);
         }
+        f.write_char(FIRST_STRONG_ISOLATE)?;
+        f.write_str(text)?;
+        f.write_char(POP_DIRECTIONAL_ISOLATE)
+    }
 
-        result
+    /// Writes the minus sign, marked so right to left text does not move it to the other side of the amount.
+    fn write_sign<W: fmt::Write>(f: &mut W, params: &Params) -> fmt::Result {
+        if params.bidi == Some(Direction::RightToLeft) {
+            f.write_char(match params.numbering_system {
+                NumberingSystem::ArabicIndic => ARABIC_LETTER_MARK,
+                _ => LEFT_TO_RIGHT_MARK,
+            })?;
+        }
+        f.write_char('-')
+    }
+
+    /// Returns the currency name that agrees with the amount: the name of its plural category if `plural_names`
+    /// has one, otherwise the singular or plural name.
+    fn name(amount: &Decimal, params: &Params) -> &'static str {
+        let category = params.plural_rule.category(amount);
+        if let Some((_, name)) = params.plural_names.iter().find(|(c, _)| *c == category) {
+            return name;
+        }
+        match category {
+            PluralCategory::One => params.name.unwrap_or(""),
+            _ => params.plural_name.or(params.name).unwrap_or(""),
+        }
     }
 
-    /// Returns a formatted digit component, given the digit string, separator and pattern of separation.
-    fn digits(raw_digits: &str, separator: char, pattern: &[usize]) -> String {
-        let mut digits = raw_digits.to_string();
+    /// Writes the unsigned amount, with separated digits and exponent, given formatting parameters.
+    fn write_amount<W: fmt::Write>(f: &mut W, amount: &Decimal, params: &Params) -> fmt::Result {
+        // Render the mantissa from right to left, a Decimal has at most 29 digits.
+        let mut buffer = [0u8; 29];
+        let mut mantissa = amount.mantissa().unsigned_abs();
+        let mut start = buffer.len();
+        loop {
+            start -= 1;
+            buffer[start] = b'0' + (mantissa % 10) as u8;
+            mantissa /= 10;
+            if mantissa == 0 {
+                break;
+            }
+        }
+
+        // Split the digits into major and minor units, e.g. 5 with a scale of 2 is 0.05.
+        let digits = &buffer[start..];
+        let scale = amount.scale() as usize;
+        let (major, minor) = if digits.len() > scale {
+            digits.split_at(digits.len() - scale)
+        } else {
+            (&b"0"[..], digits)
+        };
+
+        // Write the major units, with a separator after each group in the pattern. The last group repeats,
+        // unless it is 0.
+        let pattern = &params.separator_pattern;
+        let repeated = pattern.last().copied().unwrap_or(0);
+        for (i, digit) in major.iter().enumerate() {
+            f.write_char(params.numbering_system.digit(digit - b'0'))?;
+
+            let remaining = major.len() - i - 1;
+            let mut group_end = 0;
+            for index in 0.. {
+                group_end += match pattern.get(index) {
+                    Some(&group) => group,
+                    None if repeated > 0 => repeated,
+                    None => break,
+                };
+                match group_end.cmp(&remaining) {
+                    Ordering::Less => continue,
+                    Ordering::Equal => f.write_char(params.digit_separator)?,
+                    Ordering::Greater => {}
+                }
+                break;
+            }
+        }
 
-        let mut current_position: usize = 0;
-        for position in pattern.iter() {
-            current_position += position;
-            if digits.len() > current_position {
-                digits.insert(digits.len() - current_position, separator);
-                current_position += 1;
+        // Write the minor units, padded with zeros up to the scale.
+        if scale > 0 {
+            f.write_char(params.exponent_separator)?;
+            for _ in minor.len()..scale {
+                f.write_char(params.numbering_system.digit(0))?;
+            }
+            for digit in minor {
+                f.write_char(params.numbering_system.digit(digit - b'0'))?;
             }
         }
-        digits
+        Ok(())
     }
 }
 
@@ -87,6 +196,12 @@ pub enum Position {
     Code,
     Symbol,
     Sign,
+    /// The currency's name, pluralized to agree with the amount (e.g. 1 US Dollar, 2 US Dollars)
+    Name,
+    /// The currency's ISO numeric code (e.g. 840)
+    NumericCode,
+    /// Any fixed text (e.g. the "(approx.)" in USD 1,000.00 (approx.))
+    Literal(Cow<'static, str>),
 }
 
 /// Group of formatting parameters consumed by `Formatter`.
@@ -96,16 +211,94 @@ pub struct Params {
     pub digit_separator: char,
     /// The character that separates minor units from major units (e.g. 1,000.00)
     pub exponent_separator: char,
-    /// The grouping pattern that is applied to digits / major units (e.g. 1,000,000 vs 1,00,000)
-    pub separator_pattern: Vec<usize>,
+    /// The grouping pattern that is applied to digits / major units (e.g. 1,000,000 vs 1,00,000). The last group
+    /// size repeats, unless it is 0 (e.g. `[3, 2]` groups 1,00,00,000 and `[3, 0]` only 1000,000).
+    pub separator_pattern: Cow<'static, [usize]>,
     /// The relative positions of the elements in a currency string (e.g. -$1,000 vs $ -1,000)
-    pub positions: Vec<Position>,
+    pub positions: Cow<'static, [Position]>,
+    /// The positions of negative amounts, if they are laid out differently (e.g. $1,000 vs -$ 1,000)
+    pub negative_positions: Option<Cow<'static, [Position]>>,
+    /// The characters inserted by each `Position::Space` (e.g. a no-break space)
+    pub spacing: Spacing,
+    /// The digits that the amount is written in (e.g. 123 vs ١٢٣)
+    pub numbering_system: NumberingSystem,
     /// The number of minor unit digits should remain after Round::HalfEven is applied.
     pub rounding: Option<u32>,
     /// The symbol of the currency (e.g. $)
     pub symbol: Option<&'static str>,
+    /// Takes the symbol from the Money's currency in this style instead of `symbol` (e.g. US$)
+    pub symbol_style: Option<SymbolStyle>,
     /// The currency's ISO code (e.g. USD)
     pub code: Option<&'static str>,
+    /// The currency's ISO numeric code (e.g. 840), taken from the Money's currency if missing
+    pub numeric_code: Option<&'static str>,
+    /// The singular name of the currency (e.g. US Dollar)
+    pub name: Option<&'static str>,
+    /// The plural name of the currency (e.g. US Dollars), the singular name is used if missing
+    pub plural_name: Option<&'static str>,
+    /// The names of the currency in the plural categories which need their own form (e.g. złote for
+    /// `PluralCategory::Few` in Polish). Other categories use the singular or plural name.
+    pub plural_names: Cow<'static, [(PluralCategory, &'static str)]>,
+    /// The rule that picks the plural category of an amount, and so its name
+    pub plural_rule: PluralRule,
+    /// How negative amounts are marked (e.g. -$1.00 vs ($1.00))
+    pub negative_style: NegativeStyle,
+    /// Adds Unicode bidi isolates and marks for text in this direction, so symbols written right to left
+    /// (e.g. د.إ) don't scramble the amount. None writes no formatting characters.
+    pub bidi: Option<Direction>,
+}
+
+impl Params {
+    /// Returns the positions of a symbol and amount, given where the symbol goes and what separates them.
+    pub(crate) fn symbol_positions(symbol_first: bool, spacing: Spacing) -> &'static [Position] {
+        const SYMBOL_FIRST: &[Position] = &[Position::Sign, Position::Symbol, Position::Amount];
+        const SYMBOL_FIRST_SPACED: &[Position] = &[
+            Position::Sign,
+            Position::Symbol,
+            Position::Space,
+            Position::Amount,
+        ];
+        const SYMBOL_LAST: &[Position] = &[Position::Sign, Position::Amount, Position::Symbol];
+        const SYMBOL_LAST_SPACED: &[Position] = &[
+            Position::Sign,
+            Position::Amount,
+            Position::Space,
+            Position::Symbol,
+        ];
+
+        match (symbol_first, spacing != Spacing::None) {
+            (true, false) => SYMBOL_FIRST,
+            (true, true) => SYMBOL_FIRST_SPACED,
+            (false, false) => SYMBOL_LAST,
+            (false, true) => SYMBOL_LAST_SPACED,
+        }
+    }
+}
+
+impl From<&LocalFormat> for Params {
+    /// Creates parameters which follow a LocalFormat, without any currency details.
+    ///
+    /// Right to left formats turn on bidi formatting characters, left to right ones leave them off.
+    fn from(format: &LocalFormat) -> Params {
+        Params {
+            digit_separator: format.digit_separator,
+            exponent_separator: format.exponent_separator,
+            separator_pattern: format.digit_separator_pattern.clone(),
+            positions: Cow::Borrowed(Params::symbol_positions(
+                format.symbol_first,
+                format.symbol_spacing,
+            )),
+            spacing: format.symbol_spacing,
+            numbering_system: format.numbering_system,
+            plural_rule: format.plural_rule,
+            negative_style: format.negative_style,
+            bidi: match format.direction {
+                Direction::RightToLeft => Some(Direction::RightToLeft),
+                Direction::LeftToRight => None,
+            },
+            ..Default::default()
+        }
+    }
 }
 
 impl Default for Params {
@@ -114,11 +307,22 @@ impl Default for Params {
         Params {
             digit_separator: ',',
             exponent_separator: '.',
-            separator_pattern: vec![3, 3, 3],
-            positions: vec![Position::Sign, Position::Symbol, Position::Amount],
+            separator_pattern: Cow::Borrowed(&[3, 3, 3]),
+            positions: Cow::Borrowed(&[Position::Sign, Position::Symbol, Position::Amount]),
+            negative_positions: None,
+            spacing: Spacing::Regular,
+            numbering_system: NumberingSystem::Latin,
             rounding: None,
             symbol: None,
+            symbol_style: None,
             code: None,
+            numeric_code: None,
+            name: None,
+            plural_name: None,
+            plural_names: Cow::Borrowed(&[]),
+            plural_rule: PluralRule::English,
+            negative_style: NegativeStyle::Sign,
+            bidi: None,
         }
     }
 }
@@ -126,16 +330,18 @@ impl Default for Params {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::define_currency_set;
+    use crate::{define_currency_set, Locale};
 
     define_currency_set!(
         test {
             USD: {
                 code: "USD",
+                disambiguated_symbol: "US$",
                 exponent: 2,
                 locale: EnUs,
                 minor_units: 100,
                 name: "USD",
+                numeric_code: "840",
                 symbol: "$",
                 symbol_first: true,
             }
@@ -159,7 +365,8 @@ mod tests {
                 Position::Amount,
                 Position::Space,
                 Position::Code,
-            ],
+            ]
+            .into(),
             ..Default::default()
         };
         assert_eq!("- $1,000 USD", Formatter::money(&money, params));
@@ -174,33 +381,192 @@ mod tests {
                 Position::Symbol,
                 Position::Space,
                 Position::Sign,
-            ],
+            ]
+            .into(),
             ..Default::default()
         };
         assert_eq!("USD 1,000$ -", Formatter::money(&money, params));
 
         // Test that you can omit some, and it works fine.
         let params = Params {
-            positions: vec![Position::Amount],
+            positions: vec![Position::Amount].into(),
             ..Default::default()
         };
         assert_eq!("1,000", Formatter::money(&money, params));
 
         let params = Params {
             symbol: Some("$"),
-            positions: vec![Position::Symbol],
+            positions: vec![Position::Symbol].into(),
             ..Default::default()
         };
         assert_eq!("$", Formatter::money(&money, params));
 
         // Missing Optionals Insert Nothing
         let params = Params {
-            positions: vec![Position::Amount, Position::Symbol],
+            positions: vec![Position::Amount, Position::Symbol].into(),
             ..Default::default()
         };
         assert_eq!("1,000", Formatter::money(&money, params));
     }
 
+    #[test]
+    fn format_writes_into_buffer() {
+        let params = Params {
+            symbol: Some("$"),
+            ..Default::default()
+        };
+
+        let mut buffer = String::new();
+        for amount in [1_000, -25] {
+            let money = Money::from_major(amount, test::USD);
+            Formatter::write_money(&mut buffer, &money, &params).unwrap();
+            buffer.push(';');
+        }
+        assert_eq!("$1,000;-$25;", buffer);
+
+        let money = Money::from_str("0.05", test::USD).unwrap();
+        buffer.clear();
+        Formatter::write_money(&mut buffer, &money, &params).unwrap();
+        assert_eq!("$0.05", buffer);
+    }
+
+    #[test]
+    fn format_native_digits() {
+        let money = Money::from_minor(-123_456_789, test::USD);
+        let params = |numbering_system| Params {
+            digit_separator: '٬',
+            exponent_separator: '٫',
+            numbering_system,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            "-١٬٢٣٤٬٥٦٧٫٨٩",
+            Formatter::money(&money, params(NumberingSystem::ArabicIndic))
+        );
+        assert_eq!(
+            "-۱٬۲۳۴٬۵۶۷٫۸۹",
+            Formatter::money(&money, params(NumberingSystem::ExtendedArabicIndic))
+        );
+        assert_eq!(
+            "-१٬२३४٬५६७٫८९",
+            Formatter::money(&money, params(NumberingSystem::Devanagari))
+        );
+
+        let money = Money::from_str("0.05", test::USD).unwrap();
+        assert_eq!(
+            "٠٫٠٥",
+            Formatter::money(&money, params(NumberingSystem::ArabicIndic))
+        );
+    }
+
+    #[test]
+    fn format_literals() {
+        let money = Money::from_major(1000, test::USD);
+
+        let params = Params {
+            code: Some("USD"),
+            positions: vec![
+                Position::Code,
+                Position::Literal(Cow::Borrowed(": ")),
+                Position::Amount,
+                Position::Space,
+                Position::Literal(Cow::Owned(format!("({})", "approx."))),
+            ]
+            .into(),
+            ..Default::default()
+        };
+        assert_eq!("USD: 1,000 (approx.)", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_spacing() {
+        let money = Money::from_major(1000, test::USD);
+        let params = Params {
+            symbol: Some("$"),
+            positions: vec![Position::Amount, Position::Space, Position::Symbol].into(),
+            ..Default::default()
+        };
+
+        let expected = [
+            (Spacing::Regular, "1,000 $"),
+            (Spacing::NoBreak, "1,000\u{a0}$"),
+            (Spacing::NarrowNoBreak, "1,000\u{202f}$"),
+            (Spacing::None, "1,000$"),
+        ];
+        for (spacing, expected) in expected {
+            let params = Params {
+                spacing,
+                ..params.clone()
+            };
+            assert_eq!(expected, Formatter::money(&money, params));
+        }
+    }
+
+    #[test]
+    fn format_symbol_styles() {
+        let money = Money::from_major(1000, test::USD);
+        let params = Params {
+            symbol: Some("$"),
+            symbol_style: Some(SymbolStyle::Disambiguated),
+            ..Default::default()
+        };
+        assert_eq!("US$1,000", Formatter::money(&money, params));
+
+        let params = Params {
+            symbol_style: Some(SymbolStyle::Local(Locale::EnUs)),
+            ..Default::default()
+        };
+        assert_eq!("$1,000", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_negative_styles() {
+        let money = Money::from_major(-1000, test::USD);
+        let params = Params {
+            symbol: Some("$"),
+            ..Default::default()
+        };
+
+        let expected = [
+            (NegativeStyle::Sign, "-$1,000"),
+            (NegativeStyle::TrailingSign, "$1,000-"),
+            (NegativeStyle::Parentheses, "($1,000)"),
+        ];
+        for (negative_style, expected) in expected {
+            let params = Params {
+                negative_style,
+                ..params.clone()
+            };
+            assert_eq!(expected, Formatter::money(&money, params.clone()));
+            assert_eq!("$1,000", Formatter::money(&-money, params));
+        }
+    }
+
+    #[test]
+    fn format_with_params_from_local_format() {
+        let format = LocalFormat {
+            name: Cow::Borrowed("x-house"),
+            digit_separator: '\'',
+            digit_separator_pattern: Cow::Borrowed(&[4, 4]),
+            exponent_separator: '.',
+            plural_rule: PluralRule::English,
+            symbol_first: false,
+            symbol_spacing: Spacing::Regular,
+            numbering_system: NumberingSystem::Latin,
+            negative_style: NegativeStyle::Parentheses,
+            direction: Direction::LeftToRight,
+        };
+        let params = Params {
+            symbol: Some("$"),
+            rounding: Some(2),
+            ..Params::from(&format)
+        };
+
+        let money = Money::from_minor(-123_456_789, test::USD);
+        assert_eq!("(123'4567.89 $)", Formatter::money(&money, params));
+    }
+
     #[test]
     fn format_digit_separators_with_custom_separators() {
         let params = Params {
@@ -224,7 +590,7 @@ mod tests {
     #[test]
     fn format_digit_separators_with_custom_sequences() {
         let params = Params {
-            separator_pattern: vec![3, 2, 2],
+            separator_pattern: vec![3, 2, 2].into(),
             ..Default::default()
         };
 
@@ -235,11 +601,24 @@ mod tests {
         assert_eq!("1,00,000", Formatter::money(&money, params.clone()));
 
         let money = Money::from_major(1_000, test::USD);
-        assert_eq!("1,000", Formatter::money(&money, params));
+        assert_eq!("1,000", Formatter::money(&money, params.clone()));
+
+        // The last group repeats, unless it is 0.
+        let money = Money::from_major(1_000_000_000_000, test::USD);
+        assert_eq!("10,00,00,00,00,000", Formatter::money(&money, params));
+        assert_eq!(
+            "1,000,000,000,000",
+            Formatter::money(&money, Params::default())
+        );
+        let params = Params {
+            separator_pattern: vec![3, 0].into(),
+            ..Default::default()
+        };
+        assert_eq!("1000000000,000", Formatter::money(&money, params));
 
         // With a zero sequence
         let params = Params {
-            separator_pattern: vec![0, 2],
+            separator_pattern: vec![0, 2].into(),
             ..Default::default()
         };
 
@@ -252,6 +631,84 @@ mod tests {
 
     // What if pattern includes a zero or negative number?
 
+    #[test]
+    fn format_name_agrees_with_amount() {
+        let params = Params {
+            name: Some("US Dollar"),
+            plural_name: Some("US Dollars"),
+            positions: vec![
+                Position::Sign,
+                Position::Amount,
+                Position::Space,
+                Position::Name,
+            ]
+            .into(),
+            ..Default::default()
+        };
+
+        let money = Money::from_major(1, test::USD);
+        assert_eq!("1 US Dollar", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_major(-1, test::USD);
+        assert_eq!("-1 US Dollar", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_major(2, test::USD);
+        assert_eq!("2 US Dollars", Formatter::money(&money, params.clone()));
+
+        let money = Money::from_minor(50, test::USD);
+        assert_eq!("0.50 US Dollars", Formatter::money(&money, params.clone()));
+
+        // Visible trailing zeros make an amount plural in English.
+        let money = Money::from_minor(100, test::USD);
+        assert_eq!("1.00 US Dollars", Formatter::money(&money, params));
+
+        // Missing plural names fall back to the singular name.
+        let params = Params {
+            name: Some("Bitcoin"),
+            positions: vec![Position::Amount, Position::Space, Position::Name].into(),
+            ..Default::default()
+        };
+        let money = Money::from_minor(50, test::USD);
+        assert_eq!("0.50 Bitcoin", Formatter::money(&money, params));
+
+        // Languages with more plural forms name them by category.
+        let params = Params {
+            name: Some("złoty"),
+            plural_name: Some("złotego"),
+            plural_names: vec![
+                (PluralCategory::Few, "złote"),
+                (PluralCategory::Many, "złotych"),
+            ]
+            .into(),
+            plural_rule: PluralRule::Polish,
+            positions: vec![Position::Amount, Position::Space, Position::Name].into(),
+            ..Default::default()
+        };
+        let names = [1, 2, 5, 22]
+            .map(|amount| Formatter::money(&Money::from_major(amount, test::USD), params.clone()));
+        assert_eq!(names, ["1 złoty", "2 złote", "5 złotych", "22 złote"]);
+        let money = Money::from_minor(150, test::USD);
+        assert_eq!("1.50 złotego", Formatter::money(&money, params));
+    }
+
+    #[test]
+    fn format_numeric_code() {
+        let params = Params {
+            numeric_code: Some("840"),
+            positions: vec![Position::Amount, Position::Space, Position::NumericCode].into(),
+            ..Default::default()
+        };
+        let money = Money::from_major(1_000, test::USD);
+        assert_eq!("1,000 840", Formatter::money(&money, params));
+
+        // The numeric code defaults to the currency's.
+        let params = Params {
+            positions: vec![Position::NumericCode, Position::Space, Position::Amount].into(),
+            ..Default::default()
+        };
+        assert_eq!("840 1,000", Formatter::money(&money, params));
+    }
+
     #[test]
     fn format_rounding() {
         let money = Money::from_minor(1000, test::USD) / 3;
@@ -1,15 +1,27 @@
 #![doc = include_str!("../README.md")]
 
+mod any;
+#[cfg(feature = "cldr")]
+pub mod cldr;
 mod currency;
 mod error;
 mod exchange;
 mod format;
 mod locale;
 mod money;
+mod posix;
+mod precision;
+mod registry;
+mod table;
 
+pub use any::*;
 pub use currency::*;
 pub use error::MoneyError;
 pub use exchange::*;
 pub use format::*;
 pub use locale::*;
 pub use money::*;
+pub use posix::*;
+pub use precision::*;
+pub use registry::*;
+pub use table::*;
@@ -1,3 +1,8 @@
+use crate::MoneyError;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::borrow::Cow;
+use std::fmt;
 use std::str::FromStr;
 
 /// Enumerates regions which have unique formatting standards for Currencies.
@@ -9,55 +14,897 @@ pub enum Locale {
     EnIn,
     EnEu,
     EnBy,
+    /// Arabic (United Arab Emirates), e.g. 1,234.56 د.إ
+    ArAe,
+    /// Arabic (Saudi Arabia), e.g. ١٬٢٣٤٫٥٦ ر.س
+    ArSa,
+    /// German (Switzerland), e.g. CHF 1’234.56
+    DeCh,
+    /// German (Germany), e.g. 1.234,56 €
+    DeDe,
+    /// English (Canada), e.g. $1,234.56
+    EnCa,
+    /// Spanish (Spain), e.g. 1.234,56 €
+    EsEs,
+    /// Spanish (Mexico), e.g. $1,234.56
+    EsMx,
+    /// French (Canada), e.g. 1 234,56 $
+    FrCa,
+    /// French (France), e.g. 1 234,56 €
+    FrFr,
+    /// Hebrew (Israel), e.g. 1,234.56 ₪
+    HeIl,
+    /// Hindi (India), e.g. ₹1,23,456.78
+    HiIn,
+    /// Italian (Italy), e.g. 1.234,56 €
+    ItIt,
+    /// Japanese (Japan), e.g. ￥1,234
+    JaJp,
+    /// Korean (South Korea), e.g. ₩1,234
+    KoKr,
+    /// Dutch (Netherlands), e.g. € 1.234,56
+    NlNl,
+    /// Polish (Poland), e.g. 1 234,56 zł
+    PlPl,
+    /// Portuguese (Brazil), e.g. R$ 1.234,56
+    PtBr,
+    /// Russian (Russia), e.g. 1 234,56 ₽
+    RuRu,
+    /// Swedish (Sweden), e.g. 1 234,56 kr
+    SvSe,
+    /// Chinese (China), e.g. ¥1,234.56
+    ZhCn,
+    /// A locale generated from the CLDR snapshot in `data/cldr`.
+    #[cfg(feature = "cldr")]
+    Cldr(crate::cldr::CldrLocale),
+    /// A format defined at runtime, see `LocalFormat::as_locale`.
+    Custom(&'static LocalFormat),
+}
+
+impl Locale {
+    /// Every built-in locale, excluding those generated from CLDR data.
+    pub(crate) const BUILT_IN: &'static [Locale] = &[
+        Locale::EnUs,
+        Locale::EnIn,
+        Locale::EnEu,
+        Locale::EnBy,
+        Locale::ArAe,
+        Locale::ArSa,
+        Locale::DeCh,
+        Locale::DeDe,
+        Locale::EnCa,
+        Locale::EsEs,
+        Locale::EsMx,
+        Locale::FrCa,
+        Locale::FrFr,
+        Locale::HeIl,
+        Locale::HiIn,
+        Locale::ItIt,
+        Locale::JaJp,
+        Locale::KoKr,
+        Locale::NlNl,
+        Locale::PlPl,
+        Locale::PtBr,
+        Locale::RuRu,
+        Locale::SvSe,
+        Locale::ZhCn,
+    ];
+
+    /// The locale used for a language when the requested region is not supported.
+    const LANGUAGE_DEFAULTS: &'static [(&'static str, Locale)] = &[
+        ("ar", Locale::ArSa),
+        ("de", Locale::DeDe),
+        ("en", Locale::EnUs),
+        ("es", Locale::EsEs),
+        ("fr", Locale::FrFr),
+        ("pt", Locale::PtBr),
+    ];
+
+    fn all() -> impl Iterator<Item = Locale> {
+        let locales = Locale::BUILT_IN.iter().copied();
+        #[cfg(feature = "cldr")]
+        let locales = locales.chain(
+            crate::cldr::CldrLocale::ALL
+                .iter()
+                .map(|l| Locale::Cldr(*l)),
+        );
+        locales
+    }
+
+    /// Picks the best supported Locale from an HTTP Accept-Language header (e.g. `de-CH, fr;q=0.8, *;q=0.5`).
+    ///
+    /// Tags are tried from the highest weight down, each falling back to its language like `Locale::from_str`.
+    /// Returns None if no tag can be resolved.
+    pub fn negotiate(accept_language: &str) -> Option<Locale> {
+        let mut tags: Vec<(&str, f32)> = accept_language
+            .split(',')
+            .filter_map(|entry| {
+                let mut parts = entry.split(';').map(str::trim);
+                let tag = parts.next().filter(|tag| !tag.is_empty() && *tag != "*")?;
+                let weight = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.parse::<f32>().ok())?;
+                Some((tag, weight)).filter(|(_, weight)| *weight > 0.0)
+            })
+            .collect();
+
+        // Stable sorting keeps the header order for tags of equal weight.
+        tags.sort_by(|a, b| b.1.total_cmp(&a.1));
+        tags.into_iter().find_map(|(tag, _)| tag.parse().ok())
+    }
+}
+
+/// Parses a BCP-47 (e.g. `pt-BR`) or POSIX (e.g. `de_CH.UTF-8`) tag into the closest supported Locale.
+///
+/// Tags are matched by language and region, ignoring case, scripts and extensions. A region which is not
+/// supported falls back to the default locale of the language (e.g. `de-LU` resolves to `Locale::DeDe`).
+impl FromStr for Locale {
+    type Err = MoneyError;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        // Drop the POSIX codeset and modifier (e.g. .UTF-8 or @euro).
+        let tag = tag.split(['.', '@']).next().unwrap_or_default();
+        if tag == "C" || tag == "POSIX" {
+            return Ok(Locale::EnUs);
+        }
+
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags
+            .next()
+            .filter(|l| (2..=3).contains(&l.len()) && l.chars().all(|c| c.is_ascii_alphabetic()))
+            .ok_or(MoneyError::InvalidLocale)?;
+        // The region is the first subtag of two letters or three digits, after an optional script.
+        let region = subtags
+            .find(|s| s.len() == 2 || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit())));
+
+        // Every locale name is a language-region pair, like en-US.
+        let matches = |locale: &Locale, region: Option<&str>| {
+            let name = LocalFormat::from_locale(*locale).name;
+            let (l, r) = name.split_once('-').unwrap_or((&name, ""));
+            l.eq_ignore_ascii_case(language)
+                && region.is_none_or(|region| r.eq_ignore_ascii_case(region))
+        };
+        let same_language = |locale: &Locale| matches(locale, None);
+        let exact =
+            region.and_then(|region| Locale::all().find(|locale| matches(locale, Some(region))));
+
+        exact
+            .or_else(|| {
+                Locale::LANGUAGE_DEFAULTS
+                    .iter()
+                    .find(|(lang, _)| lang.eq_ignore_ascii_case(language))
+                    .map(|(_, locale)| *locale)
+            })
+            .or_else(|| Locale::all().find(same_language))
+            .ok_or(MoneyError::InvalidLocale)
+    }
+}
+
+/// Writes the canonical BCP-47 tag of the Locale (e.g. `pt-BR`), which is also its `LocalFormat::name`.
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&LocalFormat::from_locale(*self).name)
+    }
 }
 
 /// Stores currency formatting metadata for a specific region (e.g. EN-US).
-#[derive(Debug, PartialEq, Eq)]
+///
+/// Formats other than the built-in ones can be created at runtime and used through `Locale::Custom`.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct LocalFormat {
-    pub name: &'static str,
+    pub name: Cow<'static, str>,
     pub digit_separator: char,
-    pub digit_separator_pattern: &'static str,
+    /// The sizes of digit groups, starting from the exponent separator, whose last size repeats unless it is 0
+    /// (e.g. `[3, 2]` for 1,00,00,000)
+    pub digit_separator_pattern: Cow<'static, [usize]>,
     pub exponent_separator: char,
+    pub plural_rule: PluralRule,
+    /// Whether the currency symbol is placed before the amount (e.g. $1.00 vs 1,00 €)
+    pub symbol_first: bool,
+    /// The spacing between a currency symbol and the amount (e.g. $1.00 vs 1,00 €)
+    pub symbol_spacing: Spacing,
+    /// The digits that amounts are written in (e.g. 123 vs ١٢٣)
+    pub numbering_system: NumberingSystem,
+    /// How negative amounts are marked (e.g. -$1.00 vs ($1.00))
+    pub negative_style: NegativeStyle,
+    /// The direction text is written in, which decides the bidi marks around symbols and sign
+    pub direction: Direction,
 }
 
 impl LocalFormat {
-    /// Returns a vector indicating where digit separators should be applied on a Money amount.
-    ///
-    /// For example, `3, 3, 3` indicates that the digit separator should be applied after the 3rd, 6th and 9th digits.
-    pub fn digit_separator_pattern(&self) -> Vec<usize> {
-        let v: Vec<&str> = self.digit_separator_pattern.split(", ").collect();
-        v.iter().map(|x| usize::from_str(x).unwrap()).collect()
-    }
-
     /// Returns the associated LocalFormat given a Locale.
     pub fn from_locale(locale: Locale) -> LocalFormat {
         use Locale::*;
 
         match locale {
-            EnUs => LocalFormat {
-                name: "en-us",
+            EnEu => LocalFormat {
+                name: Cow::Borrowed("en-EU"),
+                digit_separator: '.',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::English,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            EnBy => LocalFormat {
+                name: Cow::Borrowed("en-BY"),
+                digit_separator: ' ',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::English,
+                symbol_first: false,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            // Generated from data/cldr by `cargo run --example cldr --features cldr`, do not edit.
+            ArAe => LocalFormat {
+                name: Cow::Borrowed("ar-AE"),
                 digit_separator: ',',
-                digit_separator_pattern: "3, 3, 3",
+                digit_separator_pattern: Cow::Borrowed(&[3]),
                 exponent_separator: '.',
+                plural_rule: PluralRule::Arabic,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::RightToLeft,
+            },
+            ArSa => LocalFormat {
+                name: Cow::Borrowed("ar-SA"),
+                digit_separator: '٬',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '٫',
+                plural_rule: PluralRule::Arabic,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::ArabicIndic,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::RightToLeft,
+            },
+            DeCh => LocalFormat {
+                name: Cow::Borrowed("de-CH"),
+                digit_separator: '’',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::English,
+                symbol_first: true,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            DeDe => LocalFormat {
+                name: Cow::Borrowed("de-DE"),
+                digit_separator: '.',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::English,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            EnCa => LocalFormat {
+                name: Cow::Borrowed("en-CA"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::English,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
             },
             EnIn => LocalFormat {
-                name: "en-in",
+                name: Cow::Borrowed("en-IN"),
                 digit_separator: ',',
-                digit_separator_pattern: "3, 2, 2",
+                digit_separator_pattern: Cow::Borrowed(&[3, 2]),
                 exponent_separator: '.',
+                plural_rule: PluralRule::English,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
             },
-            EnEu => LocalFormat {
-                name: "en-eu",
+            EnUs => LocalFormat {
+                name: Cow::Borrowed("en-US"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::English,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            EsEs => LocalFormat {
+                name: Cow::Borrowed("es-ES"),
                 digit_separator: '.',
-                digit_separator_pattern: "3, 3, 3",
+                digit_separator_pattern: Cow::Borrowed(&[3]),
                 exponent_separator: ',',
+                plural_rule: PluralRule::Spanish,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
             },
-            EnBy => LocalFormat {
-                name: "en-by",
-                digit_separator: ' ',
-                digit_separator_pattern: "3, 3, 3",
+            EsMx => LocalFormat {
+                name: Cow::Borrowed("es-MX"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::Spanish,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            FrCa => LocalFormat {
+                name: Cow::Borrowed("fr-CA"),
+                digit_separator: '\u{a0}',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::French,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            FrFr => LocalFormat {
+                name: Cow::Borrowed("fr-FR"),
+                digit_separator: '\u{202f}',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::French,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            HeIl => LocalFormat {
+                name: Cow::Borrowed("he-IL"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::Hebrew,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::RightToLeft,
+            },
+            HiIn => LocalFormat {
+                name: Cow::Borrowed("hi-IN"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3, 2]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::Hindi,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            ItIt => LocalFormat {
+                name: Cow::Borrowed("it-IT"),
+                digit_separator: '.',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::Italian,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            JaJp => LocalFormat {
+                name: Cow::Borrowed("ja-JP"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::Invariant,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            KoKr => LocalFormat {
+                name: Cow::Borrowed("ko-KR"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::Invariant,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            NlNl => LocalFormat {
+                name: Cow::Borrowed("nl-NL"),
+                digit_separator: '.',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::English,
+                symbol_first: true,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            PlPl => LocalFormat {
+                name: Cow::Borrowed("pl-PL"),
+                digit_separator: '\u{a0}',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::Polish,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            PtBr => LocalFormat {
+                name: Cow::Borrowed("pt-BR"),
+                digit_separator: '.',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
                 exponent_separator: ',',
+                plural_rule: PluralRule::French,
+                symbol_first: true,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
             },
+            RuRu => LocalFormat {
+                name: Cow::Borrowed("ru-RU"),
+                digit_separator: '\u{a0}',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::Russian,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            SvSe => LocalFormat {
+                name: Cow::Borrowed("sv-SE"),
+                digit_separator: '\u{a0}',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: ',',
+                plural_rule: PluralRule::English,
+                symbol_first: false,
+                symbol_spacing: Spacing::NoBreak,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            ZhCn => LocalFormat {
+                name: Cow::Borrowed("zh-CN"),
+                digit_separator: ',',
+                digit_separator_pattern: Cow::Borrowed(&[3]),
+                exponent_separator: '.',
+                plural_rule: PluralRule::Invariant,
+                symbol_first: true,
+                symbol_spacing: Spacing::None,
+                numbering_system: NumberingSystem::Latin,
+                negative_style: NegativeStyle::Sign,
+                direction: Direction::LeftToRight,
+            },
+            // End of the generated locales.
+            #[cfg(feature = "cldr")]
+            Cldr(locale) => locale.local_format(),
+            Custom(format) => format.clone(),
         }
     }
+
+    /// Returns a Locale for a format defined at runtime, which can then be used like a built-in one.
+    ///
+    /// The format must live as long as the program, e.g. in a `static`, or a `OnceLock` for formats which are
+    /// loaded at runtime.
+    pub fn as_locale(&'static self) -> Locale {
+        Locale::Custom(self)
+    }
+}
+
+/// The direction in which a locale writes text.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction {
+    LeftToRight,
+    /// Used by Arabic, Hebrew and Persian, whose amounts need bidi marks to display in the right order.
+    RightToLeft,
+}
+
+/// How negative amounts are marked.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum NegativeStyle {
+    /// A minus sign written at `Position::Sign` (e.g. -$1.00)
+    Sign,
+    /// A minus sign after the whole amount (e.g. $1.00-)
+    TrailingSign,
+    /// Parentheses around the whole amount, as used in accounting (e.g. ($1.00))
+    Parentheses,
+}
+
+/// Characters that can be used to separate the elements of a Money string.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Spacing {
+    /// A regular space (U+0020).
+    Regular,
+    /// A no-break space (U+00A0), which keeps both sides on the same line.
+    NoBreak,
+    /// A narrow no-break space (U+202F), used by French typography.
+    NarrowNoBreak,
+    /// No space at all.
+    None,
+}
+
+impl Spacing {
+    /// Returns the characters which this Spacing inserts.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Spacing::Regular => " ",
+            Spacing::NoBreak => "\u{a0}",
+            Spacing::NarrowNoBreak => "\u{202f}",
+            Spacing::None => "",
+        }
+    }
+}
+
+/// Enumerates the sets of digits that amounts can be written in.
+///
+/// Each system has ten consecutive Unicode digits, from zero to nine.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum NumberingSystem {
+    /// 0123456789
+    Latin,
+    /// ٠١٢٣٤٥٦٧٨٩, used with Arabic in the Middle East and North Africa.
+    ArabicIndic,
+    /// ۰۱۲۳۴۵۶۷۸۹, used with Persian and Urdu.
+    ExtendedArabicIndic,
+    /// ०१२३४५६७८९, used with Hindi and Marathi.
+    Devanagari,
+}
+
+impl NumberingSystem {
+    const ALL: [NumberingSystem; 4] = [
+        NumberingSystem::Latin,
+        NumberingSystem::ArabicIndic,
+        NumberingSystem::ExtendedArabicIndic,
+        NumberingSystem::Devanagari,
+    ];
+
+    /// Returns the digit which represents zero in this system.
+    fn zero(&self) -> char {
+        match self {
+            NumberingSystem::Latin => '0',
+            NumberingSystem::ArabicIndic => '\u{660}',
+            NumberingSystem::ExtendedArabicIndic => '\u{6f0}',
+            NumberingSystem::Devanagari => '\u{966}',
+        }
+    }
+
+    /// Returns the character for a digit from 0 to 9 in this system.
+    pub fn digit(&self, value: u8) -> char {
+        debug_assert!(value < 10, "A digit must be between 0 and 9");
+        char::from_u32(self.zero() as u32 + value as u32).unwrap()
+    }
+
+    /// Returns the ASCII digit for a digit of any supported system, or None if it is not a digit.
+    pub fn to_ascii_digit(digit: char) -> Option<char> {
+        NumberingSystem::ALL.iter().find_map(|system| {
+            let value = (digit as u32).checked_sub(system.zero() as u32)?;
+            match value {
+                0..=9 => char::from_digit(value, 10),
+                _ => None,
+            }
+        })
+    }
+
+    /// Replaces the digits of every supported system in a string with ASCII digits.
+    pub(crate) fn to_ascii(text: &str) -> Cow<'_, str> {
+        if text.is_ascii() {
+            return Cow::Borrowed(text);
+        }
+        text.chars()
+            .map(|c| NumberingSystem::to_ascii_digit(c).unwrap_or(c))
+            .collect()
+    }
+}
+
+/// Grammatical categories that select the plural form of a word, as defined by the Unicode CLDR.
+///
+/// For example, English uses `One` for "1 dollar" and `Other` for "2 dollars".
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+/// Enumerates the CLDR plural rules that map a number to a PluralCategory.
+///
+/// Each LocalFormat uses the rule of its language, and languages which pluralize alike share a rule.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PluralRule {
+    /// `one` for the integer 1, `other` for everything else (en, de, nl, sv).
+    English,
+    /// `one` for amounts below 2, `many` for whole millions (fr, pt-BR).
+    French,
+    /// `one` for 1 with any trailing zeros, `many` for whole millions (es).
+    Spanish,
+    /// `one` for the integer 1, `many` for whole millions (it).
+    Italian,
+    /// `one`, `few` and `many` depending on the last digits of integers (pl).
+    Polish,
+    /// `one`, `few` and `many` depending on the last digits of integers (ru, uk).
+    Russian,
+    /// `zero`, `one`, `two`, `few` and `many` depending on the last digits of integers (ar).
+    Arabic,
+    /// `one` for 0 to 1 (hi).
+    Hindi,
+    /// `one` for the integer 1 and `two` for the integer 2 (he).
+    Hebrew,
+    /// `other` for every amount (ja, ko, zh).
+    Invariant,
+}
+
+impl PluralRule {
+    /// Returns the PluralCategory of an amount, as it would be displayed.
+    ///
+    /// Trailing zeros are significant, so `1` is `One` but `1.00` is `Other` in English.
+    pub fn category(&self, amount: &Decimal) -> PluralCategory {
+        use PluralCategory::*;
+
+        let PluralOperands { n, i, v } = PluralOperands::new(amount);
+        let integer = v == 0;
+        let whole = n.fract().is_zero();
+        let (i10, i100) = (i % 10, i % 100);
+        let millions = integer && i != 0 && i % 1_000_000 == 0;
+
+        match self {
+            PluralRule::English if i == 1 && integer => One,
+            PluralRule::French if i <= 1 => One,
+            PluralRule::French | PluralRule::Spanish | PluralRule::Italian if millions => Many,
+            PluralRule::Spanish if n == Decimal::ONE => One,
+            PluralRule::Italian if i == 1 && integer => One,
+            PluralRule::Polish if i == 1 && integer => One,
+            PluralRule::Polish | PluralRule::Russian
+                if integer && (2..=4).contains(&i10) && !(12..=14).contains(&i100) =>
+            {
+                Few
+            }
+            PluralRule::Russian if integer && i10 == 1 && i100 != 11 => One,
+            PluralRule::Polish | PluralRule::Russian if integer => Many,
+            PluralRule::Arabic if whole && i == 0 => Zero,
+            PluralRule::Arabic if whole && i == 1 => One,
+            PluralRule::Arabic if whole && i == 2 => Two,
+            PluralRule::Arabic if whole && (3..=10).contains(&i100) => Few,
+            PluralRule::Arabic if whole && (11..=99).contains(&i100) => Many,
+            PluralRule::Hindi if i == 0 || n == Decimal::ONE => One,
+            PluralRule::Hebrew if (i == 1 && integer) || (i == 0 && !integer) => One,
+            PluralRule::Hebrew if i == 2 && integer => Two,
+            _ => Other,
+        }
+    }
+}
+
+/// The CLDR plural operands of a decimal number.
+///
+/// Integer digits above 10^18 are dropped from `i`, since no plural rule looks at them.
+struct PluralOperands {
+    /// The absolute value.
+    n: Decimal,
+    /// The integer digits of the absolute value.
+    i: u64,
+    /// The number of visible fraction digits, including trailing zeros.
+    v: u32,
+}
+
+impl PluralOperands {
+    fn new(amount: &Decimal) -> PluralOperands {
+        let n = amount.abs();
+        let i = (n.trunc() % Decimal::from(1_000_000_000_000_000_000u64))
+            .to_u64()
+            .unwrap_or_default();
+        PluralOperands { n, i, v: n.scale() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::*;
+
+    #[test]
+    fn numbering_systems_map_digits() {
+        assert_eq!(NumberingSystem::Latin.digit(7), '7');
+        assert_eq!(NumberingSystem::ArabicIndic.digit(7), '٧');
+        assert_eq!(NumberingSystem::ExtendedArabicIndic.digit(4), '۴');
+        assert_eq!(NumberingSystem::Devanagari.digit(9), '९');
+
+        assert_eq!(NumberingSystem::to_ascii_digit('٣'), Some('3'));
+        assert_eq!(NumberingSystem::to_ascii_digit('۰'), Some('0'));
+        assert_eq!(NumberingSystem::to_ascii_digit('५'), Some('5'));
+        assert_eq!(NumberingSystem::to_ascii_digit('8'), Some('8'));
+        assert_eq!(NumberingSystem::to_ascii_digit(','), None);
+
+        assert_eq!(NumberingSystem::to_ascii("١٬٢٣٤"), "1٬234");
+    }
+
+    #[test]
+    fn english_plural_categories() {
+        let rule = PluralRule::English;
+        assert_eq!(rule.category(&dec!(1)), PluralCategory::One);
+        assert_eq!(rule.category(&dec!(-1)), PluralCategory::One);
+        assert_eq!(rule.category(&dec!(0)), PluralCategory::Other);
+        assert_eq!(rule.category(&dec!(2)), PluralCategory::Other);
+        assert_eq!(rule.category(&dec!(0.5)), PluralCategory::Other);
+        assert_eq!(rule.category(&dec!(1.00)), PluralCategory::Other);
+    }
+
+    #[test]
+    fn romance_plural_categories() {
+        use PluralCategory::*;
+
+        let french = PluralRule::French;
+        assert_eq!(french.category(&dec!(0)), One);
+        assert_eq!(french.category(&dec!(1.50)), One);
+        assert_eq!(french.category(&dec!(2)), Other);
+        assert_eq!(french.category(&dec!(1000000)), Many);
+
+        let spanish = PluralRule::Spanish;
+        assert_eq!(spanish.category(&dec!(1.00)), One);
+        assert_eq!(spanish.category(&dec!(0)), Other);
+        assert_eq!(spanish.category(&dec!(2000000)), Many);
+
+        let italian = PluralRule::Italian;
+        assert_eq!(italian.category(&dec!(1)), One);
+        assert_eq!(italian.category(&dec!(1.00)), Other);
+        assert_eq!(italian.category(&dec!(1000000.00)), Other);
+    }
+
+    #[test]
+    fn slavic_plural_categories() {
+        use PluralCategory::*;
+
+        let russian = PluralRule::Russian;
+        let expected = [
+            (1, One),
+            (21, One),
+            (11, Many),
+            (3, Few),
+            (13, Many),
+            (25, Many),
+        ];
+        for (amount, category) in expected {
+            assert_eq!(russian.category(&Decimal::from(amount)), category);
+        }
+        assert_eq!(russian.category(&dec!(1.5)), Other);
+
+        let polish = PluralRule::Polish;
+        let expected = [(1, One), (21, Many), (22, Few), (12, Many), (5, Many)];
+        for (amount, category) in expected {
+            assert_eq!(polish.category(&Decimal::from(amount)), category);
+        }
+        assert_eq!(polish.category(&dec!(2.5)), Other);
+    }
+
+    #[test]
+    fn other_plural_categories() {
+        use PluralCategory::*;
+
+        let arabic = PluralRule::Arabic;
+        let expected = [
+            (0, Zero),
+            (1, One),
+            (2, Two),
+            (5, Few),
+            (105, Few),
+            (11, Many),
+            (100, Other),
+        ];
+        for (amount, category) in expected {
+            assert_eq!(arabic.category(&Decimal::from(amount)), category);
+        }
+
+        let hebrew = PluralRule::Hebrew;
+        assert_eq!(hebrew.category(&Decimal::from(1)), One);
+        assert_eq!(hebrew.category(&Decimal::from(2)), Two);
+        assert_eq!(hebrew.category(&Decimal::new(5, 1)), One);
+        assert_eq!(hebrew.category(&Decimal::from(20)), Other);
+        assert_eq!(arabic.category(&dec!(2.00)), Two);
+        assert_eq!(arabic.category(&dec!(2.5)), Other);
+
+        let hindi = PluralRule::Hindi;
+        assert_eq!(hindi.category(&dec!(0.5)), One);
+        assert_eq!(hindi.category(&dec!(1.00)), One);
+        assert_eq!(hindi.category(&dec!(1.5)), Other);
+
+        assert_eq!(PluralRule::Invariant.category(&dec!(1)), Other);
+    }
+
+    #[test]
+    fn locales_have_distinct_formats() {
+        let swiss = LocalFormat::from_locale(Locale::DeCh);
+        assert_eq!(swiss.digit_separator, '’');
+        assert_eq!(swiss.exponent_separator, '.');
+
+        let french = LocalFormat::from_locale(Locale::FrFr);
+        assert_eq!(french.digit_separator, '\u{202f}');
+        assert_eq!(french.symbol_spacing, Spacing::NoBreak);
+        assert!(!french.symbol_first);
+
+        let arabic = LocalFormat::from_locale(Locale::ArSa);
+        assert_eq!(arabic.numbering_system, NumberingSystem::ArabicIndic);
+    }
+
+    #[test]
+    fn locales_parse_from_tags() {
+        assert_eq!("en-US".parse(), Ok(Locale::EnUs));
+        assert_eq!("pt-br".parse(), Ok(Locale::PtBr));
+        assert_eq!("de_CH.UTF-8".parse(), Ok(Locale::DeCh));
+        assert_eq!("fr_FR@euro".parse(), Ok(Locale::FrFr));
+        assert_eq!("zh-Hans-CN".parse(), Ok(Locale::ZhCn));
+        assert_eq!("C".parse(), Ok(Locale::EnUs));
+
+        // Unsupported regions fall back to the language.
+        assert_eq!("de-LU".parse(), Ok(Locale::DeDe));
+        assert_eq!("es-419".parse(), Ok(Locale::EsEs));
+        assert_eq!("ja".parse(), Ok(Locale::JaJp));
+        #[cfg(not(feature = "cldr"))]
+        assert_eq!("en-GB".parse(), Ok(Locale::EnUs));
+        #[cfg(feature = "cldr")]
+        assert_eq!(
+            "en-GB".parse(),
+            Ok(Locale::Cldr(crate::cldr::CldrLocale::EnGb))
+        );
+
+        assert_eq!("xx-US".parse::<Locale>(), Err(MoneyError::InvalidLocale));
+        assert_eq!("".parse::<Locale>(), Err(MoneyError::InvalidLocale));
+        assert_eq!("123".parse::<Locale>(), Err(MoneyError::InvalidLocale));
+    }
+
+    #[test]
+    fn locales_display_their_canonical_tag() {
+        for locale in Locale::all() {
+            assert_eq!(locale.to_string(), LocalFormat::from_locale(locale).name);
+            assert_eq!(locale.to_string().parse(), Ok(locale));
+        }
+        assert_eq!(Locale::PtBr.to_string(), "pt-BR");
+    }
+
+    #[test]
+    fn locales_negotiate_accept_language() {
+        assert_eq!(
+            Locale::negotiate("fr-LU, fr;q=0.9, en;q=0.8"),
+            Some(Locale::FrFr)
+        );
+        assert_eq!(
+            Locale::negotiate("xx, de-CH;q=0.5, ja;q=0.7"),
+            Some(Locale::JaJp)
+        );
+        assert_eq!(Locale::negotiate("en;q=0.5, sv-SE"), Some(Locale::SvSe));
+        assert_eq!(Locale::negotiate("ko;q=0, pl;q=0.1"), Some(Locale::PlPl));
+        assert_eq!(Locale::negotiate("*"), None);
+        assert_eq!(Locale::negotiate(""), None);
+    }
 }
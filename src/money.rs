@@ -1,8 +1,10 @@
-use crate::currency::FormattableCurrency;
-use crate::format::{Formatter, Params, Position};
-use crate::locale::LocalFormat;
+use crate::currency::{FormattableCurrency, SymbolStyle};
+use crate::format::{Formatter, Params};
+use crate::locale::{LocalFormat, Locale, NegativeStyle, NumberingSystem};
+use crate::precision::PrecisionProfile;
 use crate::MoneyError;
 
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt;
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
@@ -165,19 +167,50 @@ impl<'a, T: FormattableCurrency> Ord for Money<'a, T> {
 impl<'a, T: FormattableCurrency> Money<'a, T> {
     /// Creates a Money object given an amount string and a currency str.
     ///
-    /// Supports fuzzy amount strings like "100", "100.00" and "-100.00"
+    /// Supports fuzzy amount strings like "100", "100.00" and "-100.00", written with the digits of any
+    /// `NumberingSystem` (e.g. "١٠٠").
     pub fn from_str(amount: &str, currency: &'a T) -> Result<Money<'a, T>, MoneyError> {
         let format = LocalFormat::from_locale(currency.locale());
+        Money::from_str_with_format(amount, currency, &format)
+    }
+
+    /// Creates a Money object given an amount string written in a LocalFormat, and a currency.
+    ///
+    /// Unlike `from_str`, the separators come from the given format rather than the currency's locale, and
+    /// negative amounts may be marked the way the format writes them (e.g. "(100.00)").
+    pub fn from_str_with_format(
+        amount: &str,
+        currency: &'a T,
+        format: &LocalFormat,
+    ) -> Result<Money<'a, T>, MoneyError> {
+        let amount = NumberingSystem::to_ascii(amount);
+        let (amount, negative) = match format.negative_style {
+            NegativeStyle::Sign => (&*amount, false),
+            NegativeStyle::TrailingSign => match amount.strip_suffix('-') {
+                Some(amount) => (amount, true),
+                None => (&*amount, false),
+            },
+            NegativeStyle::Parentheses => {
+                match amount.strip_prefix('(').and_then(|a| a.strip_suffix(')')) {
+                    Some(amount) => (amount, true),
+                    None => (&*amount, false),
+                }
+            }
+        };
         let amount_parts: Vec<&str> = amount.split(format.exponent_separator).collect();
 
         let mut split_decimal: Vec<&str> = amount_parts[0].split(format.digit_separator).collect();
         let mut parsed_decimal = split_decimal.concat();
 
-        // Sanity check the decimal seperation
-        for &num in format.digit_separator_pattern().iter() {
+        // Sanity check the decimal seperation, repeating the last group of the pattern
+        let pattern = &format.digit_separator_pattern;
+        for index in 0.. {
             if split_decimal.len() <= 1 {
                 break;
             }
+            let Some(&num) = pattern.get(index).or(pattern.last()) else {
+                break;
+            };
             let current = split_decimal.pop().unwrap();
             if current.len() != num {
                 return Err(MoneyError::InvalidAmount);
@@ -196,8 +229,11 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
             return Err(MoneyError::InvalidAmount);
         }
 
-        let decimal = Decimal::from_str(&parsed_decimal).unwrap();
-        Ok(Money::from_decimal(decimal, currency))
+        let decimal = Decimal::from_str(&parsed_decimal).map_err(|_| MoneyError::InvalidAmount)?;
+        Ok(Money::from_decimal(
+            if negative { -decimal } else { decimal },
+            currency,
+        ))
     }
 
     /// Creates a Money object given an integer and a currency reference.
@@ -322,46 +358,130 @@ impl<'a, T: FormattableCurrency> Money<'a, T> {
 
         money
     }
+
+    /// Returns a `Money` rounded to the decimal places, or the multiple of the smallest coin, a precision profile
+    /// allows for its currency, using the rounding of the profile.
+    pub fn round_for(&self, profile: &PrecisionProfile) -> Money<'a, T> {
+        match profile.increment(self.currency) {
+            Some(increment) => {
+                let coins = Money::from_decimal(self.amount / increment, self.currency)
+                    .round(0, profile.round());
+                Money::from_decimal(coins.amount * increment, self.currency)
+            }
+            None => self.round(profile.exponent(self.currency), profile.round()),
+        }
+    }
+
+    /// Returns `InvalidAmount` if the amount has more decimal places than a precision profile allows for its
+    /// currency, or is not a multiple of its smallest coin. Trailing zeros are ignored.
+    pub fn validate_for(&self, profile: &PrecisionProfile) -> Result<(), MoneyError> {
+        if self.amount.normalize().scale() > profile.exponent(self.currency) {
+            return Err(MoneyError::InvalidAmount);
+        }
+        if let Some(increment) = profile.increment(self.currency) {
+            if !(self.amount % increment).is_zero() {
+                return Err(MoneyError::InvalidAmount);
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Strategies that can be used to round Money.
 ///
 /// For more details, see [rust_decimal::RoundingStrategy]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum Round {
     HalfUp,
     HalfDown,
     HalfEven,
 }
 
-impl<'a, T: FormattableCurrency + FormattableCurrency> fmt::Display for Money<'a, T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let currency = self.currency;
-        let format = LocalFormat::from_locale(currency.locale());
+impl<'a, T: FormattableCurrency> Money<'a, T> {
+    /// Returns a value which displays the Money in the given locale instead of the currency's own.
+    ///
+    /// The locale decides separators, symbol placement and spacing, while the currency provides the symbol
+    /// and exponent (e.g. EUR in `Locale::EnUs` is €1,234.56 rather than €1.234,56). Symbols shared by several
+    /// currencies are disambiguated outside the currency's own locale (e.g. USD in `Locale::EnCa` is US$1.00).
+    pub fn format_in(&self, locale: Locale) -> LocalizedMoney<'_, 'a, T> {
+        LocalizedMoney {
+            money: self,
+            locale,
+        }
+    }
 
-        let mut format_params = Params {
-            digit_separator: format.digit_separator,
-            exponent_separator: format.exponent_separator,
-            separator_pattern: format.digit_separator_pattern(),
-            rounding: Some(currency.exponent()),
-            symbol: Some(currency.symbol()),
+    /// Returns a String of the Money formatted in the given locale.
+    pub fn to_string_with_locale(&self, locale: Locale) -> String {
+        self.format_in(locale).to_string()
+    }
+
+    fn write(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        format: &LocalFormat,
+        symbol_first: bool,
+        symbol_style: SymbolStyle,
+    ) -> fmt::Result {
+        let currency = self.currency;
+        let format_params = Params {
+            positions: Cow::Borrowed(Params::symbol_positions(
+                symbol_first,
+                format.symbol_spacing,
+            )),
+            rounding: currency.has_fixed_exponent().then(|| currency.exponent()),
+            symbol: Some(currency.styled_symbol(symbol_style)),
             code: Some(currency.code()),
-            ..Default::default()
+            numeric_code: currency.numeric_code(),
+            name: Some(currency.name()),
+            plural_name: Some(currency.plural_name()),
+            ..Params::from(format)
         };
 
-        if currency.symbol_first() {
-            format_params.positions = vec![Position::Sign, Position::Symbol, Position::Amount];
-            write!(f, "{}", Formatter::money(self, format_params))
+        Formatter::write_money(f, self, &format_params)
+    }
+}
+
+/// Formats Money in the locale of its currency.
+///
+/// The alternate flag (`{:#}`) displays the disambiguated symbol of the currency (e.g. US$1.00 instead of $1.00).
+impl<'a, T: FormattableCurrency + FormattableCurrency> fmt::Display for Money<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = LocalFormat::from_locale(self.currency.locale());
+        let style = if f.alternate() {
+            SymbolStyle::Disambiguated
         } else {
-            format_params.positions = vec![Position::Sign, Position::Amount, Position::Symbol];
-            write!(f, "{}", Formatter::money(self, format_params))
-        }
+            SymbolStyle::Standard
+        };
+        self.write(f, &format, self.currency.symbol_first(), style)
+    }
+}
+
+/// Displays a Money in a locale other than its currency's, created by `Money::format_in`.
+///
+/// The symbol is the one readers of the locale expect (see `SymbolStyle::Local`), or the disambiguated symbol
+/// with the alternate flag (`{:#}`).
+#[derive(Debug, Clone, Copy)]
+pub struct LocalizedMoney<'m, 'a, T: FormattableCurrency> {
+    money: &'m Money<'a, T>,
+    locale: Locale,
+}
+
+impl<'m, 'a, T: FormattableCurrency> fmt::Display for LocalizedMoney<'m, 'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = LocalFormat::from_locale(self.locale);
+        let style = if f.alternate() {
+            SymbolStyle::Disambiguated
+        } else {
+            SymbolStyle::Local(self.locale)
+        };
+        self.money.write(f, &format, format.symbol_first, style)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::define_currency_set;
+    use crate::{define_currency_set, Direction, PluralRule, Spacing};
 
     define_currency_set!(
         test {
@@ -462,6 +582,27 @@ mod tests {
         assert_eq!(money, expected_money);
     }
 
+    #[test]
+    fn money_from_string_parses_native_digits() {
+        let expected_money = Money::from_minor(123_456, test::GBP);
+        assert_eq!(
+            Money::from_str("١,٢٣٤.٥٦", test::GBP).unwrap(),
+            expected_money
+        );
+        assert_eq!(
+            Money::from_str("۱,۲۳۴.۵۶", test::GBP).unwrap(),
+            expected_money
+        );
+        assert_eq!(
+            Money::from_str("१,२३४.५६", test::INR).unwrap(),
+            Money::from_minor(123_456, test::INR)
+        );
+
+        // Native digits are still checked against the digit separator pattern.
+        let money = Money::from_str("١,٢٣.٤٥", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+    }
+
     #[test]
     fn money_from_string_decimal_sanity() {
         let money = Money::from_str("1,00.00", test::GBP);
@@ -481,6 +622,12 @@ mod tests {
 
         let money = Money::from_str("1.000.000.00", test::INR);
         assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+
+        // Groups past the end of the pattern repeat its last size.
+        let money = Money::from_str("1,000,000,0000,000.00", test::GBP);
+        assert_eq!(money.unwrap_err(), MoneyError::InvalidAmount);
+        let money = Money::from_str("1,000,000,000,000.00", test::GBP);
+        assert_eq!(money.unwrap().amount(), &Decimal::new(1_000_000_000_000, 0));
     }
 
     #[test]
@@ -764,6 +911,14 @@ mod tests {
         let inr = Money::from_minor(-1_000_000_000, test::INR); // - 1 Crore Rupees
         let expected_inr_fmt = "-₹1,00,00,000.00";
         assert_eq!(format!("{}", inr), expected_inr_fmt);
+
+        let usd = Money::from_minor(100_000_000_000_000, test::USD); // 1 Trillion Dollars
+        let expected_usd_fmt = "$1,000,000,000,000.00";
+        assert_eq!(format!("{}", usd), expected_usd_fmt);
+
+        let inr = Money::from_minor(100_000_000_000_000, test::INR); // 1 Lakh Crore Rupees
+        let expected_inr_fmt = "₹10,00,00,00,00,000.00";
+        assert_eq!(format!("{}", inr), expected_inr_fmt);
     }
 
     #[test]
@@ -784,6 +939,60 @@ mod tests {
         assert_eq!(format!("{}", money), expected_fmt);
     }
 
+    #[test]
+    fn money_fmt_in_viewer_locale() {
+        let money = Money::from_minor(123_456, test::EUR);
+        assert_eq!(money.to_string(), "€1.234,56");
+        assert_eq!(money.to_string_with_locale(Locale::EnUs), "€1,234.56");
+        assert_eq!(money.to_string_with_locale(Locale::DeDe), "1.234,56\u{a0}€");
+        assert_eq!(
+            money.to_string_with_locale(Locale::FrFr),
+            "1\u{202f}234,56\u{a0}€"
+        );
+        assert_eq!(
+            format!("{}", money.format_in(Locale::NlNl)),
+            "€\u{a0}1.234,56"
+        );
+
+        // The exponent of the currency is kept whatever the locale.
+        let money = Money::from_minor(-1_234_567, test::BHD);
+        assert_eq!(
+            money.to_string_with_locale(Locale::DeDe),
+            "-1.234,567\u{a0}ب.د"
+        );
+    }
+
+    #[test]
+    fn money_custom_locale() {
+        static FORMAT: LocalFormat = LocalFormat {
+            name: Cow::Borrowed("en-x-house"),
+            digit_separator: ' ',
+            digit_separator_pattern: Cow::Borrowed(&[3, 3, 3]),
+            exponent_separator: '.',
+            plural_rule: PluralRule::English,
+            symbol_first: false,
+            symbol_spacing: Spacing::Regular,
+            numbering_system: NumberingSystem::Latin,
+            negative_style: NegativeStyle::TrailingSign,
+            direction: Direction::LeftToRight,
+        };
+        let locale = FORMAT.as_locale();
+        assert_eq!(locale.to_string(), "en-x-house");
+        assert_eq!(LocalFormat::from_locale(locale), FORMAT);
+
+        let money = Money::from_minor(-123_456, test::EUR);
+        assert_eq!(money.to_string_with_locale(locale), "1 234.56 €-");
+
+        let parsed = Money::from_str_with_format("1 234.56-", test::EUR, &FORMAT).unwrap();
+        assert_eq!(parsed, money);
+        let parsed = Money::from_str_with_format("1 234.56", test::EUR, &FORMAT).unwrap();
+        assert_eq!(parsed, -money);
+        assert_eq!(
+            Money::from_str_with_format("1,234.56", test::EUR, &FORMAT).unwrap_err(),
+            MoneyError::InvalidAmount
+        );
+    }
+
     #[test]
     // Dividing 20 by 3 rounds to 6.67 in USD and 6.667 in BHD
     fn money_precision_and_rounding() {
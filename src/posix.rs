@@ -0,0 +1,484 @@
+use crate::format::{Params, Position};
+use crate::locale::{
+    Direction, LocalFormat, Locale, NegativeStyle, NumberingSystem, PluralRule, Spacing,
+};
+use crate::MoneyError;
+
+use std::borrow::Cow;
+
+/// Stores the monetary conventions of a POSIX locale, as defined by its LC_MONETARY category.
+///
+/// Field names and meanings follow POSIX and glibc's locale source files (e.g. `/usr/share/i18n/locales/de_DE`).
+/// Fields missing from a definition keep their default value.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LcMonetary {
+    /// The international currency symbol, followed by its separator (e.g. "EUR ")
+    pub int_curr_symbol: String,
+    /// The local currency symbol (e.g. €)
+    pub currency_symbol: String,
+    pub mon_decimal_point: String,
+    pub mon_thousands_sep: String,
+    /// The sizes of digit groups from the decimal point, where the last size repeats and -1 stops grouping
+    pub mon_grouping: Vec<i32>,
+    pub positive_sign: String,
+    pub negative_sign: String,
+    pub int_frac_digits: i32,
+    pub frac_digits: i32,
+    /// Whether the symbol precedes positive amounts
+    pub p_cs_precedes: bool,
+    /// 0 for no space between symbol and positive amounts, 1 for a space, 2 for a space next to the sign
+    pub p_sep_by_space: u8,
+    pub n_cs_precedes: bool,
+    pub n_sep_by_space: u8,
+    /// Where the sign goes: 0 parentheses, 1 before amount and symbol, 2 after them, 3 before and 4 after the symbol
+    pub p_sign_posn: u8,
+    pub n_sign_posn: u8,
+}
+
+impl LcMonetary {
+    /// Parses the LC_MONETARY category out of a locale source file.
+    ///
+    /// Returns `InvalidLocale` if the file has no LC_MONETARY category, if it only copies another locale's, or if
+    /// a value is malformed.
+    pub fn parse(source: &str) -> Result<LcMonetary, MoneyError> {
+        let (lines, escape_char) = LcMonetary::lines(source);
+        let mut monetary = LcMonetary::default();
+        let mut in_category = false;
+        let mut found = false;
+
+        for line in lines.iter() {
+            let (keyword, value) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let value = value.trim();
+
+            if !in_category {
+                if keyword == "LC_MONETARY" {
+                    in_category = true;
+                    found = true;
+                }
+                continue;
+            }
+
+            let string = || LcMonetary::string(value, escape_char);
+            let number = || value.parse::<i32>().map_err(|_| MoneyError::InvalidLocale);
+            let flag = || match number()? {
+                0 | -1 => Ok(false),
+                1 => Ok(true),
+                _ => Err(MoneyError::InvalidLocale),
+            };
+            let small = || u8::try_from(number()?).map_err(|_| MoneyError::InvalidLocale);
+
+            match keyword {
+                "END" => in_category = false,
+                "copy" => return Err(MoneyError::InvalidLocale),
+                "int_curr_symbol" => monetary.int_curr_symbol = string()?,
+                "currency_symbol" => monetary.currency_symbol = string()?,
+                "mon_decimal_point" => monetary.mon_decimal_point = string()?,
+                "mon_thousands_sep" => monetary.mon_thousands_sep = string()?,
+                "mon_grouping" => {
+                    monetary.mon_grouping = value
+                        .split(';')
+                        .map(|size| size.trim().parse().map_err(|_| MoneyError::InvalidLocale))
+                        .collect::<Result<_, _>>()?
+                }
+                "positive_sign" => monetary.positive_sign = string()?,
+                "negative_sign" => monetary.negative_sign = string()?,
+                "int_frac_digits" => monetary.int_frac_digits = number()?,
+                "frac_digits" => monetary.frac_digits = number()?,
+                "p_cs_precedes" => monetary.p_cs_precedes = flag()?,
+                "p_sep_by_space" => monetary.p_sep_by_space = small()?,
+                "n_cs_precedes" => monetary.n_cs_precedes = flag()?,
+                "n_sep_by_space" => monetary.n_sep_by_space = small()?,
+                "p_sign_posn" => monetary.p_sign_posn = small()?,
+                "n_sign_posn" => monetary.n_sign_posn = small()?,
+                // Other keywords (e.g. int_p_cs_precedes) have no equivalent in Params.
+                _ => {}
+            }
+        }
+
+        if found {
+            Ok(monetary)
+        } else {
+            Err(MoneyError::InvalidLocale)
+        }
+    }
+
+    /// Returns Params which format amounts following these conventions.
+    ///
+    /// Positive amounts are laid out with the `p_*` fields and `positive_sign`, negative amounts with the `n_*` fields
+    /// and `negative_sign`, or a minus sign if it is empty, as `strfmon` does. The symbol is left to the caller, since
+    /// it depends on the currency being formatted.
+    pub fn to_params(&self) -> Params {
+        let positive_sign = Some(Position::Literal(Cow::Owned(self.positive_sign.clone())))
+            .filter(|_| !self.positive_sign.is_empty());
+        let positions = LcMonetary::positions(
+            positive_sign,
+            self.p_cs_precedes,
+            self.p_sep_by_space,
+            self.p_sign_posn,
+        );
+        let negative_sign = match self.negative_sign.as_str() {
+            "" => Position::Sign,
+            sign => Position::Literal(Cow::Owned(sign.to_string())),
+        };
+        let negative_positions = LcMonetary::positions(
+            Some(negative_sign),
+            self.n_cs_precedes,
+            self.n_sep_by_space,
+            self.n_sign_posn,
+        );
+
+        Params {
+            digit_separator: self.mon_thousands_sep.chars().next().unwrap_or(','),
+            exponent_separator: self.mon_decimal_point.chars().next().unwrap_or('.'),
+            separator_pattern: Cow::Owned(self.separator_pattern()),
+            positions: Cow::Owned(positions),
+            negative_positions: Some(Cow::Owned(negative_positions)),
+            spacing: Spacing::Regular,
+            numbering_system: NumberingSystem::Latin,
+            rounding: u32::try_from(self.frac_digits).ok(),
+            negative_style: match self.n_sign_posn {
+                0 => NegativeStyle::Parentheses,
+                _ => NegativeStyle::Sign,
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Returns a LocalFormat with these conventions, which can be used as a `Locale::Custom`.
+    ///
+    /// The name should be a BCP-47 tag (e.g. de-DE), which also picks the plural rule and direction. A LocalFormat has
+    /// a single layout, taken from the `p_*` fields, and only spaces the symbol from the amount: with a
+    /// `p_sep_by_space` of 2, which spaces the symbol from the sign instead, they are not separated. Negative amounts
+    /// are written with a minus sign rather than `negative_sign`.
+    pub fn into_local_format(self, name: impl Into<Cow<'static, str>>) -> LocalFormat {
+        let name = name.into();
+        let base = name.parse::<Locale>().ok().map(LocalFormat::from_locale);
+        LocalFormat {
+            name,
+            digit_separator: self.mon_thousands_sep.chars().next().unwrap_or(','),
+            digit_separator_pattern: Cow::Owned(self.separator_pattern()),
+            exponent_separator: self.mon_decimal_point.chars().next().unwrap_or('.'),
+            plural_rule: base
+                .as_ref()
+                .map_or(PluralRule::English, |base| base.plural_rule),
+            symbol_first: self.p_cs_precedes,
+            symbol_spacing: if self.p_sep_by_space == 1 {
+                Spacing::Regular
+            } else {
+                Spacing::None
+            },
+            numbering_system: NumberingSystem::Latin,
+            negative_style: self.negative_style(),
+            direction: base.map_or(Direction::LeftToRight, |base| base.direction),
+        }
+    }
+
+    /// Lays out a sign, the symbol and the amount following `cs_precedes`, `sep_by_space` and `sign_posn`.
+    fn positions(
+        sign: Option<Position>,
+        cs_precedes: bool,
+        sep_by_space: u8,
+        sign_posn: u8,
+    ) -> Vec<Position> {
+        #[derive(PartialEq, Clone, Copy)]
+        enum Item {
+            Sign,
+            Symbol,
+            Amount,
+        }
+
+        let mut items = if cs_precedes {
+            vec![Item::Symbol, Item::Amount]
+        } else {
+            vec![Item::Amount, Item::Symbol]
+        };
+        let symbol = usize::from(!cs_precedes);
+        match (&sign, sign_posn) {
+            (None, _) | (_, 0) => {}
+            (_, 2) => items.push(Item::Sign),
+            (_, 3) => items.insert(symbol, Item::Sign),
+            (_, 4) => items.insert(symbol + 1, Item::Sign),
+            _ => items.insert(0, Item::Sign),
+        }
+
+        // The space goes between the two items at this index and the next.
+        let between = |a: Item, b: Item| {
+            items
+                .windows(2)
+                .position(|pair| pair.contains(&a) && pair.contains(&b))
+        };
+        let sign_by_symbol = between(Item::Sign, Item::Symbol);
+        let space = match sep_by_space {
+            // A sign next to the symbol is spaced from the amount along with it.
+            1 if sign_by_symbol.is_some() => items
+                .windows(2)
+                .position(|pair| pair.contains(&Item::Amount)),
+            1 => between(Item::Symbol, Item::Amount),
+            2 if sign_by_symbol.is_some() => sign_by_symbol,
+            2 => between(Item::Sign, Item::Amount),
+            _ => None,
+        };
+
+        let mut positions = vec![];
+        for (i, item) in items.into_iter().enumerate() {
+            positions.push(match item {
+                Item::Sign => sign.clone().expect("Only a sign is laid out as one"),
+                Item::Symbol => Position::Symbol,
+                Item::Amount => Position::Amount,
+            });
+            if space == Some(i) {
+                positions.push(Position::Space);
+            }
+        }
+        positions
+    }
+
+    fn negative_style(&self) -> NegativeStyle {
+        match self.n_sign_posn {
+            0 => NegativeStyle::Parentheses,
+            2 => NegativeStyle::TrailingSign,
+            _ => NegativeStyle::Sign,
+        }
+    }
+
+    /// Converts mon_grouping, whose last size repeats like the sizes of a separator pattern. Sizes of -1 or
+    /// CHAR_MAX stop grouping, which a last size of 0 does in a pattern. Digits are not grouped without a
+    /// thousands separator.
+    fn separator_pattern(&self) -> Vec<usize> {
+        let mut pattern = vec![];
+        if self.mon_thousands_sep.is_empty() {
+            return pattern;
+        }
+        for &size in self.mon_grouping.iter() {
+            if size <= 0 || size >= 127 {
+                if !pattern.is_empty() {
+                    pattern.push(0);
+                }
+                break;
+            }
+            pattern.push(size as usize);
+        }
+        pattern
+    }
+
+    /// Returns the logical lines of a locale file, without comments and with continuations joined, and the
+    /// escape character the file declares.
+    fn lines(source: &str) -> (Vec<String>, char) {
+        let mut comment_char = '#';
+        let mut escape_char = '\\';
+        let mut lines = vec![];
+        let mut current = String::new();
+        for line in source.lines() {
+            let line = line.trim();
+            // comment_char and escape_char apply from the line that declares them.
+            if let Some(value) = line.strip_prefix("comment_char") {
+                comment_char = value.trim().chars().next().unwrap_or(comment_char);
+            } else if let Some(value) = line.strip_prefix("escape_char") {
+                escape_char = value.trim().chars().next().unwrap_or(escape_char);
+            }
+            if current.is_empty() && line.starts_with(comment_char) {
+                continue;
+            }
+
+            match line.strip_suffix(escape_char) {
+                Some(start) => current.push_str(start),
+                None => {
+                    current.push_str(line);
+                    if !current.is_empty() {
+                        lines.push(std::mem::take(&mut current));
+                    }
+                }
+            }
+        }
+        (lines, escape_char)
+    }
+
+    /// Decodes a quoted string, which may contain `<Uxxxx>` code points and escaped characters.
+    fn string(value: &str, escape_char: char) -> Result<String, MoneyError> {
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .ok_or(MoneyError::InvalidLocale)?;
+
+        let mut result = String::new();
+        let mut chars = value.chars();
+        while let Some(c) = chars.next() {
+            if c == escape_char {
+                result.push(chars.next().ok_or(MoneyError::InvalidLocale)?);
+            } else if c == '<' {
+                let symbol: String = chars.by_ref().take_while(|c| *c != '>').collect();
+                let code = symbol
+                    .strip_prefix('U')
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .and_then(char::from_u32)
+                    .ok_or(MoneyError::InvalidLocale)?;
+                result.push(code);
+            } else {
+                result.push(c);
+            }
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{define_currency_set, Formatter, Money};
+    use std::sync::OnceLock;
+
+    define_currency_set!(
+        test {
+            EUR: {
+                code: "EUR",
+                exponent: 2,
+                locale: EnEu,
+                minor_units: 1,
+                name: "Euro",
+                symbol: "€",
+                symbol_first: true,
+            }
+        }
+    );
+
+    fn format(source: &str, minor: i64) -> String {
+        let params = Params {
+            symbol: Some(test::find("EUR").unwrap().symbol),
+            ..LcMonetary::parse(source).unwrap().to_params()
+        };
+        Formatter::money(&Money::from_minor(minor, test::EUR), params)
+    }
+
+    #[test]
+    fn parses_lc_monetary() {
+        let monetary = LcMonetary::parse(include_str!("../data/posix/de_DE")).unwrap();
+        assert_eq!(monetary.int_curr_symbol, "EUR ");
+        assert_eq!(monetary.currency_symbol, "€");
+        assert_eq!(monetary.mon_decimal_point, ",");
+        assert_eq!(monetary.mon_thousands_sep, ".");
+        assert_eq!(monetary.mon_grouping, vec![3, 3]);
+        assert_eq!(monetary.negative_sign, "-");
+        assert_eq!(monetary.frac_digits, 2);
+        assert!(!monetary.p_cs_precedes);
+        assert_eq!(monetary.p_sep_by_space, 1);
+        assert_eq!(monetary.n_sign_posn, 1);
+
+        let monetary = LcMonetary::parse(include_str!("../data/posix/en_IN")).unwrap();
+        assert_eq!(monetary.int_curr_symbol, "INR ");
+        assert_eq!(monetary.currency_symbol, "₹");
+    }
+
+    #[test]
+    fn formats_with_lc_monetary() {
+        let de_de = include_str!("../data/posix/de_DE");
+        assert_eq!(format(de_de, -123_456_789), "-1.234.567,89 €");
+
+        let en_us = include_str!("../data/posix/en_US");
+        assert_eq!(format(en_us, -123_456_789), "-€1,234,567.89");
+
+        let en_in = include_str!("../data/posix/en_IN");
+        assert_eq!(format(en_in, 123_456_789), "€12,34,567.89");
+
+        let de_ch = include_str!("../data/posix/de_CH");
+        assert_eq!(format(de_ch, -123_456_789), "€- 1’234’567.89");
+        assert_eq!(format(de_ch, 123_456_789), "€ 1’234’567.89");
+    }
+
+    #[test]
+    fn lays_out_signs_and_spaces() {
+        let source = |fields: &str| {
+            [
+                "LC_MONETARY\nmon_decimal_point \".\"\nmon_thousands_sep \",\"\nmon_grouping 3\nfrac_digits 2\n",
+                fields,
+                "END LC_MONETARY\n",
+            ]
+            .concat()
+        };
+
+        // Positive amounts follow the p_* fields and negative amounts the n_* fields.
+        let signed = source(
+            "positive_sign \"+\"\np_cs_precedes 0\np_sep_by_space 1\np_sign_posn 1\n\
+             n_cs_precedes 1\nn_sep_by_space 2\nn_sign_posn 1\n",
+        );
+        assert_eq!(format(&signed, 123_456), "+1,234.56 €");
+        assert_eq!(format(&signed, -123_456), "- €1,234.56");
+
+        // A sep_by_space of 2 spaces the sign from the symbol next to it, or else from the amount.
+        let trailing = source("n_cs_precedes 1\nn_sep_by_space 2\nn_sign_posn 2\n");
+        assert_eq!(format(&trailing, -123_456), "€1,234.56 -");
+        let after_symbol = source("n_cs_precedes 0\nn_sep_by_space 2\nn_sign_posn 4\n");
+        assert_eq!(format(&after_symbol, -123_456), "1,234.56€ -");
+
+        // A sep_by_space of 1 spaces the sign and symbol together from the amount.
+        let before_symbol = source("n_cs_precedes 0\nn_sep_by_space 1\nn_sign_posn 3\n");
+        assert_eq!(format(&before_symbol, -123_456), "1,234.56 -€");
+
+        // Negative amounts are written with negative_sign.
+        let minus =
+            source("negative_sign \"<U2212>\"\nn_cs_precedes 1\nn_sep_by_space 0\nn_sign_posn 1\n");
+        assert_eq!(format(&minus, -123_456), "\u{2212}€1,234.56");
+        assert_eq!(format(&minus, 123_456), "1,234.56€");
+        let parentheses = source("negative_sign \"\"\nn_cs_precedes 1\nn_sign_posn 0\n");
+        assert_eq!(format(&parentheses, -123_456), "(€1,234.56)");
+
+        // Without a thousands separator, digits are not grouped.
+        let ungrouped = source("").replace("mon_thousands_sep \",\"", "mon_thousands_sep \"\"");
+        assert_eq!(format(&ungrouped, 123_456_789), "1234567.89€");
+    }
+
+    #[test]
+    fn parses_escapes_and_continuations() {
+        let source = r#"
+escape_char /
+comment_char %
+% An accounting style, with parentheses around negative amounts.
+LC_MONETARY
+currency_symbol     "<U20AC>"
+mon_decimal_point   "/,"
+mon_thousands_sep   "<U0020>"
+mon_grouping        3;/
+                    -1
+frac_digits         2
+p_cs_precedes       0
+n_cs_precedes       0
+n_sep_by_space      0
+n_sign_posn         0
+END LC_MONETARY
+"#;
+        let monetary = LcMonetary::parse(source).unwrap();
+        assert_eq!(monetary.mon_decimal_point, ",");
+        assert_eq!(monetary.mon_grouping, vec![3, -1]);
+        assert_eq!(format(source, -123_456_789), "(1234 567,89€)");
+    }
+
+    #[test]
+    fn converts_to_local_format() {
+        let monetary = LcMonetary::parse(include_str!("../data/posix/de_DE")).unwrap();
+        static FORMAT: OnceLock<LocalFormat> = OnceLock::new();
+        let format = FORMAT.get_or_init(|| monetary.into_local_format("de-DE"));
+        assert_eq!(format.digit_separator, '.');
+        assert_eq!(format.exponent_separator, ',');
+        assert_eq!(*format.digit_separator_pattern, [3, 3]);
+        assert_eq!(format.plural_rule, PluralRule::English);
+        assert!(!format.symbol_first);
+        assert_eq!(format.symbol_spacing, Spacing::Regular);
+
+        let money = Money::from_minor(-123_456, test::EUR);
+        assert_eq!(
+            money.to_string_with_locale(format.as_locale()),
+            "-1.234,56 €"
+        );
+    }
+
+    #[test]
+    fn rejects_missing_or_copied_categories() {
+        let copied = "LC_MONETARY\ncopy \"de_DE\"\nEND LC_MONETARY\n";
+        assert_eq!(LcMonetary::parse(copied), Err(MoneyError::InvalidLocale));
+
+        let numeric = "LC_NUMERIC\ndecimal_point \".\"\nEND LC_NUMERIC\n";
+        assert_eq!(LcMonetary::parse(numeric), Err(MoneyError::InvalidLocale));
+
+        let unquoted = "LC_MONETARY\ncurrency_symbol €\nEND LC_MONETARY\n";
+        assert_eq!(LcMonetary::parse(unquoted), Err(MoneyError::InvalidLocale));
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{FormattableCurrency, Round};
+use rust_decimal::Decimal;
+use std::borrow::Cow;
+
+/// Selects how many decimal places amounts of a currency may have.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum PrecisionProfile {
+    /// The exponent of the currency, which ISO 4217 defines for ISO currencies.
+    Iso,
+    /// The decimal places of cash payments, and the smallest coin of some currencies.
+    Cash(CashPrecision),
+    /// The decimal places a payment processor accepts, where they differ from the exponent.
+    Processor(ProcessorPrecision),
+}
+
+/// Rounds cash amounts to the cash exponent of their currency, or to a multiple of its smallest coin where one is
+/// given (e.g. 0.05 for CHF, whose cash exponent is 2).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CashPrecision {
+    /// Currency codes and the smallest amount paid in cash for them.
+    pub increments: Cow<'static, [(&'static str, Decimal)]>,
+    /// How amounts halfway between two coins are rounded. Defaults to half up.
+    pub round: Round,
+}
+
+impl Default for CashPrecision {
+    fn default() -> Self {
+        CashPrecision {
+            increments: Cow::Borrowed(&[]),
+            round: Round::HalfUp,
+        }
+    }
+}
+
+/// Overrides the decimal places of some currencies for a payment processor (e.g. 0 for TWD, whose ISO 4217
+/// exponent is 2). Other currencies keep their exponent.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ProcessorPrecision {
+    /// Currency codes and the decimal places accepted for them.
+    pub exponents: Cow<'static, [(&'static str, u32)]>,
+    /// How amounts halfway between two accepted amounts are rounded. Defaults to half even.
+    pub round: Round,
+}
+
+impl Default for ProcessorPrecision {
+    fn default() -> Self {
+        ProcessorPrecision {
+            exponents: Cow::Borrowed(&[]),
+            round: Round::HalfEven,
+        }
+    }
+}
+
+impl PrecisionProfile {
+    /// Returns the decimal places of a currency under this profile.
+    pub fn exponent<T: FormattableCurrency>(&self, currency: &T) -> u32 {
+        match self {
+            PrecisionProfile::Iso => currency.exponent(),
+            PrecisionProfile::Cash(_) => match self.increment(currency) {
+                Some(increment) => increment.normalize().scale(),
+                None => currency.cash_exponent(),
+            },
+            PrecisionProfile::Processor(processor) => processor
+                .exponents
+                .iter()
+                .find(|(code, _)| *code == currency.code())
+                .map_or(currency.exponent(), |(_, exponent)| *exponent),
+        }
+    }
+
+    /// Returns the smallest amount of a currency under this profile, when it is not a power of ten (e.g. 0.05 for
+    /// CHF in cash).
+    pub fn increment<T: FormattableCurrency>(&self, currency: &T) -> Option<Decimal> {
+        match self {
+            PrecisionProfile::Cash(cash) => cash
+                .increments
+                .iter()
+                .find(|(code, increment)| *code == currency.code() && !increment.is_zero())
+                .map(|(_, increment)| *increment),
+            _ => None,
+        }
+    }
+
+    /// Returns how amounts are rounded under this profile: half even for ISO 4217, and the rounding of the
+    /// cash or processor precision otherwise.
+    pub fn round(&self) -> Round {
+        match self {
+            PrecisionProfile::Iso => Round::HalfEven,
+            PrecisionProfile::Cash(cash) => cash.round,
+            PrecisionProfile::Processor(processor) => processor.round,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{define_currency_set, Money, MoneyError, Round};
+    use rust_decimal_macros::*;
+
+    define_currency_set!(
+        test {
+            SEK: {
+                cash_exponent: 0,
+                code: "SEK",
+                exponent: 2,
+                locale: SvSe,
+                minor_units: 100,
+                name: "Swedish Krona",
+                symbol: "kr",
+                symbol_first: false,
+            },
+            USD: {
+                code: "USD",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 100,
+                name: "US Dollar",
+                symbol: "$",
+                symbol_first: true,
+            }
+        }
+    );
+
+    const PROCESSOR: &[(&str, u32)] = &[("USD", 0), ("XYZ", 4)];
+
+    #[test]
+    fn profiles_pick_exponents() {
+        let processor = PrecisionProfile::Processor(ProcessorPrecision {
+            exponents: PROCESSOR.into(),
+            ..Default::default()
+        });
+        let cash = PrecisionProfile::Cash(CashPrecision::default());
+
+        assert_eq!(PrecisionProfile::Iso.exponent(test::SEK), 2);
+        assert_eq!(cash.exponent(test::SEK), 0);
+        assert_eq!(cash.exponent(test::USD), 2);
+        assert_eq!(processor.exponent(test::USD), 0);
+        assert_eq!(processor.exponent(test::SEK), 2);
+
+        let nickels = PrecisionProfile::Cash(CashPrecision {
+            increments: vec![("USD", dec!(0.05))].into(),
+            ..Default::default()
+        });
+        assert_eq!(nickels.exponent(test::USD), 2);
+        assert_eq!(nickels.increment(test::USD), Some(dec!(0.05)));
+        assert_eq!(nickels.increment(test::SEK), None);
+        assert_eq!(processor.increment(test::USD), None);
+
+        assert_eq!(PrecisionProfile::Iso.round(), Round::HalfEven);
+        assert_eq!(cash.round(), Round::HalfUp);
+        assert_eq!(processor.round(), Round::HalfEven);
+    }
+
+    #[test]
+    fn money_rounds_and_validates_for_profiles() {
+        let cash = PrecisionProfile::Cash(CashPrecision::default());
+        let money = Money::from_decimal(dec!(10.50), test::SEK);
+        assert_eq!(money.round_for(&PrecisionProfile::Iso), money);
+        assert_eq!(money.round_for(&cash).amount(), &dec!(11));
+        assert_eq!(
+            Money::from_decimal(dec!(11.50), test::SEK)
+                .round_for(&cash)
+                .amount(),
+            &dec!(12)
+        );
+
+        assert_eq!(money.validate_for(&PrecisionProfile::Iso), Ok(()));
+        assert_eq!(money.validate_for(&cash), Err(MoneyError::InvalidAmount));
+        assert_eq!(
+            Money::from_decimal(dec!(10.00), test::SEK).validate_for(&cash),
+            Ok(())
+        );
+        assert_eq!(
+            Money::from_decimal(dec!(1.005), test::USD).validate_for(&PrecisionProfile::Iso),
+            Err(MoneyError::InvalidAmount)
+        );
+
+        let processor = PrecisionProfile::Processor(ProcessorPrecision {
+            exponents: vec![("USD", 0)].into(),
+            ..Default::default()
+        });
+        let dollars = Money::from_decimal(dec!(2.50), test::USD);
+        assert_eq!(dollars.round_for(&processor).amount(), &dec!(2));
+        assert_eq!(
+            dollars.validate_for(&processor),
+            Err(MoneyError::InvalidAmount)
+        );
+        let processor = PrecisionProfile::Processor(ProcessorPrecision {
+            exponents: vec![("USD", 0)].into(),
+            round: Round::HalfUp,
+        });
+        assert_eq!(dollars.round_for(&processor).amount(), &dec!(3));
+    }
+
+    #[test]
+    fn cash_rounds_to_the_smallest_coin() {
+        let cash = PrecisionProfile::Cash(CashPrecision {
+            increments: vec![("USD", dec!(0.05))].into(),
+            ..Default::default()
+        });
+        let round = |amount| {
+            *Money::from_decimal(amount, test::USD)
+                .round_for(&cash)
+                .amount()
+        };
+        assert_eq!(round(dec!(10.57)), dec!(10.55));
+        assert_eq!(round(dec!(10.575)), dec!(10.60));
+        assert_eq!(round(dec!(10.58)), dec!(10.60));
+        assert_eq!(round(dec!(-10.575)), dec!(-10.60));
+
+        let down = PrecisionProfile::Cash(CashPrecision {
+            increments: vec![("USD", dec!(0.05))].into(),
+            round: Round::HalfDown,
+        });
+        assert_eq!(
+            Money::from_decimal(dec!(10.575), test::USD)
+                .round_for(&down)
+                .amount(),
+            &dec!(10.55)
+        );
+
+        assert_eq!(
+            Money::from_decimal(dec!(10.55), test::USD).validate_for(&cash),
+            Ok(())
+        );
+        assert_eq!(
+            Money::from_decimal(dec!(10.57), test::USD).validate_for(&cash),
+            Err(MoneyError::InvalidAmount)
+        );
+    }
+}
@@ -0,0 +1,648 @@
+use crate::{FormattableCurrency, LocalFormat, Locale, MoneyError};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+/// A currency loaded at runtime into a `CurrencyRegistry`.
+///
+/// Its strings are leaked when it is registered, so it can be used with `Money`, `Formatter` and `Exchange` like
+/// the currencies of static sets. Currencies and strings are interned, so loading the same definitions again (e.g.
+/// when a configuration is reloaded) reuses them, and only definitions which were never seen before use more memory.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct RegisteredCurrency {
+    pub code: &'static str,
+    pub numeric_code: Option<&'static str>,
+    pub exponent: u32,
+    pub locale: Locale,
+    pub minor_units: u64,
+    pub minor_unit_name: Option<&'static str>,
+    pub name: &'static str,
+    pub plural_name: &'static str,
+    pub symbol: &'static str,
+    pub narrow_symbol: &'static str,
+    pub disambiguated_symbol: &'static str,
+    pub symbol_first: bool,
+}
+
+impl FormattableCurrency for RegisteredCurrency {
+    fn to_string(&self) -> String {
+        self.code().to_string()
+    }
+
+    fn exponent(&self) -> u32 {
+        self.exponent
+    }
+
+    fn code(&self) -> &'static str {
+        self.code
+    }
+
+    fn locale(&self) -> Locale {
+        self.locale
+    }
+
+    fn minor_units(&self) -> u64 {
+        self.minor_units
+    }
+
+    fn numeric_code(&self) -> Option<&'static str> {
+        self.numeric_code
+    }
+
+    fn minor_unit_name(&self) -> Option<&'static str> {
+        self.minor_unit_name
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn plural_name(&self) -> &'static str {
+        self.plural_name
+    }
+
+    fn symbol(&self) -> &'static str {
+        self.symbol
+    }
+
+    fn narrow_symbol(&self) -> &'static str {
+        self.narrow_symbol
+    }
+
+    fn disambiguated_symbol(&self) -> &'static str {
+        self.disambiguated_symbol
+    }
+
+    fn symbol_first(&self) -> bool {
+        self.symbol_first
+    }
+}
+
+impl fmt::Display for RegisteredCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code)
+    }
+}
+
+/// Describes a currency to add to a `CurrencyRegistry`.
+///
+/// Only `code`, `exponent`, `name` and `symbol` are required. The locale defaults to en-US, `minor_units` to
+/// 10 to the power of the exponent, the plural name to the name, the narrow and disambiguated symbols to the
+/// symbol and `symbol_first` to the convention of the locale.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+#[cfg_attr(any(feature = "json", feature = "toml"), derive(serde::Deserialize))]
+pub struct CurrencyDefinition {
+    pub code: String,
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub numeric_code: Option<String>,
+    pub exponent: u32,
+    /// A BCP-47 or POSIX tag, parsed with `Locale::from_str`.
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub locale: Option<String>,
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub minor_units: Option<u64>,
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub minor_unit_name: Option<String>,
+    pub name: String,
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub plural_name: Option<String>,
+    pub symbol: String,
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub narrow_symbol: Option<String>,
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub disambiguated_symbol: Option<String>,
+    #[cfg_attr(any(feature = "json", feature = "toml"), serde(default))]
+    pub symbol_first: Option<bool>,
+}
+
+impl CurrencyDefinition {
+    /// Sets the field named by a CSV column. Empty values leave optional fields unset.
+    fn set(&mut self, column: &str, value: String) -> Result<(), MoneyError> {
+        let optional = if value.is_empty() {
+            None
+        } else {
+            Some(value.clone())
+        };
+        match column {
+            "code" => self.code = value,
+            "numeric_code" => self.numeric_code = optional,
+            "exponent" => self.exponent = value.parse().map_err(|_| MoneyError::InvalidCurrency)?,
+            "locale" => self.locale = optional,
+            "minor_units" => {
+                self.minor_units = optional
+                    .map(|units| units.parse())
+                    .transpose()
+                    .map_err(|_| MoneyError::InvalidCurrency)?
+            }
+            "minor_unit_name" => self.minor_unit_name = optional,
+            "name" => self.name = value,
+            "plural_name" => self.plural_name = optional,
+            "symbol" => self.symbol = value,
+            "narrow_symbol" => self.narrow_symbol = optional,
+            "disambiguated_symbol" => self.disambiguated_symbol = optional,
+            "symbol_first" => {
+                self.symbol_first = optional
+                    .map(|first| first.parse())
+                    .transpose()
+                    .map_err(|_| MoneyError::InvalidCurrency)?
+            }
+            _ => return Err(MoneyError::InvalidCurrency),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+#[derive(serde::Deserialize)]
+struct Definitions {
+    currencies: Vec<CurrencyDefinition>,
+}
+
+/// A set of currencies defined at runtime, with lookups by code, numeric code and symbol.
+#[derive(Debug, Default, Clone)]
+pub struct CurrencyRegistry {
+    currencies: Vec<&'static RegisteredCurrency>,
+    codes: HashMap<String, &'static RegisteredCurrency>,
+    numeric_codes: HashMap<&'static str, &'static RegisteredCurrency>,
+    symbols: HashMap<&'static str, Vec<&'static RegisteredCurrency>>,
+}
+
+impl CurrencyRegistry {
+    pub fn new() -> CurrencyRegistry {
+        CurrencyRegistry::default()
+    }
+
+    /// Adds a currency to the registry.
+    ///
+    /// Registering a currency identical to one already registered returns it. Returns `InvalidCurrency` if the code
+    /// or name is empty, or the code or numeric code is already registered to a different currency, and
+    /// `InvalidLocale` if the locale is not recognized.
+    pub fn register(
+        &mut self,
+        definition: CurrencyDefinition,
+    ) -> Result<&'static RegisteredCurrency, MoneyError> {
+        let code = definition.code.trim().to_ascii_uppercase();
+        if code.is_empty() || definition.name.is_empty() {
+            return Err(MoneyError::InvalidCurrency);
+        }
+        let locale = match &definition.locale {
+            Some(tag) => tag.parse()?,
+            None => Locale::EnUs,
+        };
+        let minor_units = match definition.minor_units {
+            Some(units) => units,
+            None => 10u64
+                .checked_pow(definition.exponent)
+                .ok_or(MoneyError::InvalidCurrency)?,
+        };
+
+        let plural_name = definition
+            .plural_name
+            .unwrap_or_else(|| definition.name.clone());
+        let narrow_symbol = definition
+            .narrow_symbol
+            .unwrap_or_else(|| definition.symbol.clone());
+        let disambiguated_symbol = definition
+            .disambiguated_symbol
+            .unwrap_or_else(|| definition.symbol.clone());
+        let symbol_first = definition
+            .symbol_first
+            .unwrap_or_else(|| LocalFormat::from_locale(locale).symbol_first);
+
+        // Definitions are checked before their strings are interned, so rejected ones are not kept.
+        if let Some(registered) = self.codes.get(&code) {
+            let identical = registered.numeric_code == definition.numeric_code.as_deref()
+                && registered.exponent == definition.exponent
+                && registered.locale == locale
+                && registered.minor_units == minor_units
+                && registered.minor_unit_name == definition.minor_unit_name.as_deref()
+                && registered.name == definition.name
+                && registered.plural_name == plural_name
+                && registered.symbol == definition.symbol
+                && registered.narrow_symbol == narrow_symbol
+                && registered.disambiguated_symbol == disambiguated_symbol
+                && registered.symbol_first == symbol_first;
+            return if identical {
+                Ok(*registered)
+            } else {
+                Err(MoneyError::InvalidCurrency)
+            };
+        }
+        if let Some(numeric_code) = &definition.numeric_code {
+            if self.numeric_codes.contains_key(numeric_code.as_str()) {
+                return Err(MoneyError::InvalidCurrency);
+            }
+        }
+
+        let currency = RegisteredCurrency {
+            code: intern(code.clone()),
+            numeric_code: definition.numeric_code.map(intern),
+            exponent: definition.exponent,
+            locale,
+            minor_units,
+            minor_unit_name: definition.minor_unit_name.map(intern),
+            name: intern(definition.name),
+            plural_name: intern(plural_name),
+            symbol: intern(definition.symbol),
+            narrow_symbol: intern(narrow_symbol),
+            disambiguated_symbol: intern(disambiguated_symbol),
+            symbol_first,
+        };
+        let currency = intern_currency(currency);
+        self.currencies.push(currency);
+        self.codes.insert(code, currency);
+        if let Some(numeric_code) = currency.numeric_code {
+            self.numeric_codes.insert(numeric_code, currency);
+        }
+        self.symbols
+            .entry(currency.symbol)
+            .or_default()
+            .push(currency);
+        Ok(currency)
+    }
+
+    /// Returns the currency with an alphabetic code, ignoring case.
+    pub fn find(&self, code: &str) -> Option<&'static RegisteredCurrency> {
+        self.codes.get(&code.trim().to_ascii_uppercase()).copied()
+    }
+
+    pub fn find_by_num_code(&self, code: &str) -> Option<&'static RegisteredCurrency> {
+        self.numeric_codes.get(code).copied()
+    }
+
+    /// Returns every currency with a symbol, in the order they were registered.
+    pub fn find_by_symbol(&self, symbol: &str) -> &[&'static RegisteredCurrency] {
+        self.symbols.get(symbol).map_or(&[], Vec::as_slice)
+    }
+
+    /// Iterates over the currencies in the order they were registered.
+    pub fn iter(&self) -> impl Iterator<Item = &'static RegisteredCurrency> + '_ {
+        self.currencies.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.currencies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.currencies.is_empty()
+    }
+
+    /// Loads currencies from CSV with a header row naming the `CurrencyDefinition` fields of each column.
+    ///
+    /// Fields may be quoted with `"`, and quotes inside them are doubled.
+    pub fn from_csv(source: &str) -> Result<CurrencyRegistry, MoneyError> {
+        let mut rows = csv_rows(source)?.into_iter();
+        let header = rows.next().ok_or(MoneyError::InvalidCurrency)?;
+        let mut registry = CurrencyRegistry::new();
+        for row in rows {
+            if row.len() != header.len() {
+                return Err(MoneyError::InvalidCurrency);
+            }
+            let mut definition = CurrencyDefinition::default();
+            for (column, value) in header.iter().zip(row) {
+                definition.set(column.trim(), value)?;
+            }
+            registry.register(definition)?;
+        }
+        Ok(registry)
+    }
+
+    /// Loads currencies from a JSON object whose `currencies` array holds `CurrencyDefinition`s.
+    #[cfg(feature = "json")]
+    pub fn from_json(source: &str) -> Result<CurrencyRegistry, MoneyError> {
+        let definitions: Definitions =
+            serde_json::from_str(source).map_err(|_| MoneyError::InvalidCurrency)?;
+        CurrencyRegistry::from_definitions(definitions.currencies)
+    }
+
+    /// Loads currencies from a TOML document whose `[[currencies]]` tables are `CurrencyDefinition`s.
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<CurrencyRegistry, MoneyError> {
+        let definitions: Definitions =
+            toml::from_str(source).map_err(|_| MoneyError::InvalidCurrency)?;
+        CurrencyRegistry::from_definitions(definitions.currencies)
+    }
+
+    pub fn from_definitions(
+        definitions: impl IntoIterator<Item = CurrencyDefinition>,
+    ) -> Result<CurrencyRegistry, MoneyError> {
+        let mut registry = CurrencyRegistry::new();
+        for definition in definitions {
+            registry.register(definition)?;
+        }
+        Ok(registry)
+    }
+}
+
+/// The strings of every registered currency.
+static STRINGS: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+/// Returns a leaked copy of a string, shared with every registry which registered the same string before.
+fn intern(value: String) -> &'static str {
+    let mut strings = STRINGS.get_or_init(Default::default).lock().unwrap();
+    match strings.get(value.as_str()) {
+        Some(interned) => interned,
+        None => {
+            let interned = Box::leak(value.into_boxed_str());
+            strings.insert(interned);
+            interned
+        }
+    }
+}
+
+/// Returns a leaked copy of a currency, shared with every registry which registered the same currency before.
+fn intern_currency(currency: RegisteredCurrency) -> &'static RegisteredCurrency {
+    static CURRENCIES: OnceLock<Mutex<HashSet<&'static RegisteredCurrency>>> = OnceLock::new();
+    let mut currencies = CURRENCIES.get_or_init(Default::default).lock().unwrap();
+    match currencies.get(&currency) {
+        Some(interned) => interned,
+        None => {
+            let interned = Box::leak(Box::new(currency));
+            currencies.insert(interned);
+            interned
+        }
+    }
+}
+
+/// Splits CSV into rows of fields, skipping blank lines.
+fn csv_rows(source: &str) -> Result<Vec<Vec<String>>, MoneyError> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' if quoted => quoted = false,
+            '"' if field.is_empty() => quoted = true,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                if row.len() > 1 || !row[0].is_empty() {
+                    rows.push(std::mem::take(&mut row));
+                } else {
+                    row.clear();
+                }
+            }
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(MoneyError::InvalidCurrency);
+    }
+    if !row.is_empty() || !field.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Exchange, ExchangeRate, Formatter, Money, Params, SymbolStyle};
+    use rust_decimal_macros::*;
+
+    const CSV: &str =
+        "code,numeric_code,exponent,locale,name,plural_name,symbol,disambiguated_symbol,minor_unit_name\n\
+        xau,959,4,,Gold Ounce,Gold Ounces,oz,,\n\
+        GEM,,0,de-DE,Gem,Gems,G,\"G\"\"\",\n\
+        \n\
+        GLD,,2,,\"Gold, Coins\",,G,,Gold Cent\n";
+
+    #[test]
+    fn registry_loads_csv() {
+        let registry = CurrencyRegistry::from_csv(CSV).unwrap();
+        assert_eq!(registry.len(), 3);
+
+        let gold = registry.find("Xau").unwrap();
+        assert_eq!(gold.code, "XAU");
+        assert_eq!(gold.numeric_code, Some("959"));
+        assert_eq!(gold.minor_units, 10_000);
+        assert_eq!(gold.disambiguated_symbol, "oz");
+        assert!(gold.symbol_first);
+
+        let gem = registry.find("GEM").unwrap();
+        assert_eq!(gem.locale, Locale::DeDe);
+        assert!(!gem.symbol_first);
+        assert_eq!(gem.disambiguated_symbol, "G\"");
+        assert_eq!(registry.find("GLD").unwrap().name, "Gold, Coins");
+        assert_eq!(registry.find("GLD").unwrap().plural_name, "Gold, Coins");
+        assert_eq!(
+            registry.find("GLD").unwrap().minor_unit_name(),
+            Some("Gold Cent")
+        );
+        assert_eq!(gold.minor_unit_name, None);
+        assert_eq!(gold.numeric_code(), Some("959"));
+
+        assert_eq!(registry.find_by_num_code("959"), Some(gold));
+        assert_eq!(
+            registry.find_by_symbol("G"),
+            [gem, registry.find("GLD").unwrap()]
+        );
+        assert!(registry.find_by_symbol("$").is_empty());
+        assert_eq!(
+            registry.iter().map(|c| c.code).collect::<Vec<_>>(),
+            ["XAU", "GEM", "GLD"]
+        );
+    }
+
+    #[test]
+    fn registry_rejects_invalid_definitions() {
+        let invalid = [
+            "code,exponent,name,symbol\nXAU,two,Gold,oz",
+            "code,exponent,name,symbol\nXAU,2,Gold",
+            "code,exponent,name,color\nXAU,2,Gold,gold",
+            "code,exponent,name,symbol\nXAU,2,\"Gold,oz",
+            "code,exponent,name,symbol\nXAU,2,Gold,oz\nxau,3,Gold,oz",
+            "code,exponent,name,symbol\n,2,Gold,oz",
+        ];
+        for source in invalid {
+            assert_eq!(
+                CurrencyRegistry::from_csv(source).unwrap_err(),
+                MoneyError::InvalidCurrency,
+                "{}",
+                source
+            );
+        }
+        assert_eq!(
+            CurrencyRegistry::from_csv("code,exponent,name,symbol,locale\nXAU,2,Gold,oz,xx-YY")
+                .unwrap_err(),
+            MoneyError::InvalidLocale
+        );
+
+        let mut registry = CurrencyRegistry::new();
+        let definition = CurrencyDefinition {
+            code: "XAU".into(),
+            numeric_code: Some("959".into()),
+            name: "Gold".into(),
+            symbol: "oz".into(),
+            ..Default::default()
+        };
+        let gold = registry.register(definition.clone()).unwrap();
+        let duplicate = CurrencyDefinition {
+            code: "XAG".into(),
+            ..definition.clone()
+        };
+        assert_eq!(
+            registry.register(duplicate),
+            Err(MoneyError::InvalidCurrency)
+        );
+        assert_eq!(
+            registry.register(CurrencyDefinition {
+                exponent: 4,
+                ..definition.clone()
+            }),
+            Err(MoneyError::InvalidCurrency)
+        );
+        assert_eq!(registry.register(definition), Ok(gold));
+    }
+
+    #[test]
+    fn registry_keeps_no_strings_of_rejected_currencies() {
+        let mut registry = CurrencyRegistry::from_csv(CSV).unwrap();
+        let rejected = [
+            ("GEM", None, "Rejected Gem"),
+            ("GEN", Some("959"), "Rejected Numeric Code"),
+            ("", None, "Rejected Empty Code"),
+        ];
+        for (code, numeric_code, name) in rejected {
+            let definition = CurrencyDefinition {
+                code: code.into(),
+                numeric_code: numeric_code.map(String::from),
+                name: name.into(),
+                symbol: "Rejected Symbol".into(),
+                ..Default::default()
+            };
+            assert_eq!(
+                registry.register(definition),
+                Err(MoneyError::InvalidCurrency)
+            );
+        }
+
+        let strings = STRINGS.get().unwrap().lock().unwrap();
+        assert!(strings.iter().all(|s| !s.starts_with("Rejected")));
+        assert!(!strings.contains("GEN"));
+    }
+
+    #[test]
+    fn registry_reuses_identical_currencies() {
+        let mut registry = CurrencyRegistry::new();
+        let definition = CurrencyDefinition {
+            code: "xpd".into(),
+            exponent: 4,
+            name: "Palladium Ounce".into(),
+            symbol: "oz".into(),
+            ..Default::default()
+        };
+        let palladium = registry.register(definition.clone()).unwrap();
+        assert!(std::ptr::eq(
+            registry.register(definition.clone()).unwrap(),
+            palladium
+        ));
+        assert_eq!(registry.len(), 1);
+
+        // Reloading the same definitions reuses the currencies registered before.
+        let reloaded = CurrencyRegistry::from_definitions([definition]).unwrap();
+        assert!(std::ptr::eq(reloaded.find("XPD").unwrap(), palladium));
+        assert!(std::ptr::eq(
+            CurrencyRegistry::from_csv(CSV)
+                .unwrap()
+                .find("GEM")
+                .unwrap(),
+            CurrencyRegistry::from_csv(CSV)
+                .unwrap()
+                .find("GEM")
+                .unwrap()
+        ));
+    }
+
+    #[test]
+    fn registered_currencies_work_like_static_currencies() {
+        let registry = CurrencyRegistry::from_csv(CSV).unwrap();
+        let gold = registry.find("XAU").unwrap();
+        let gem = registry.find("GEM").unwrap();
+
+        let money = Money::from_str("1,234.5", gold).unwrap();
+        assert_eq!(money.amount(), &dec!(1234.5));
+        assert_eq!(money.to_string(), "oz1,234.5");
+        assert_eq!(format!("{}", Money::from_major(1_234, gem)), "1.234\u{a0}G");
+        assert_eq!(
+            format!("{:#}", Money::from_major(1_234, gem)),
+            "1.234\u{a0}G\""
+        );
+
+        let params = Params {
+            symbol: Some(gold.symbol),
+            symbol_style: Some(SymbolStyle::Disambiguated),
+            ..Default::default()
+        };
+        assert_eq!(Formatter::money(&Money::from_major(2, gold), params), "oz2");
+
+        let rate = ExchangeRate::new(gold, gem, dec!(3)).unwrap();
+        let mut exchange = Exchange::new();
+        exchange.set_rate(&rate);
+        let converted = exchange
+            .get_rate(gold, gem)
+            .unwrap()
+            .convert(&Money::from_major(2, gold))
+            .unwrap();
+        assert_eq!(converted, Money::from_major(6, gem));
+        assert!(exchange.get_rate(gem, gold).is_none());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn registry_loads_json() {
+        let registry = CurrencyRegistry::from_json(
+            r#"{"currencies": [
+                {"code": "XAU", "numeric_code": "959", "exponent": 4, "name": "Gold Ounce", "symbol": "oz"},
+                {"code": "GEM", "exponent": 0, "locale": "de_DE", "name": "Gem", "symbol": "G", "symbol_first": true}
+            ]}"#,
+        )
+        .unwrap();
+        assert_eq!(registry.find_by_num_code("959").unwrap().exponent, 4);
+        let gem = registry.find("GEM").unwrap();
+        assert_eq!(gem.locale, Locale::DeDe);
+        assert!(gem.symbol_first);
+
+        assert_eq!(
+            CurrencyRegistry::from_json(r#"{"currencies": [{"code": "XAU"}]}"#).unwrap_err(),
+            MoneyError::InvalidCurrency
+        );
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn registry_loads_toml() {
+        let registry = CurrencyRegistry::from_toml(
+            r#"
+            [[currencies]]
+            code = "XAU"
+            exponent = 4
+            name = "Gold Ounce"
+            plural_name = "Gold Ounces"
+            symbol = "oz"
+
+            [[currencies]]
+            code = "XAG"
+            exponent = 4
+            name = "Silver Ounce"
+            symbol = "oz"
+            disambiguated_symbol = "oz Ag"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(registry.find("XAU").unwrap().plural_name, "Gold Ounces");
+        assert_eq!(registry.find_by_symbol("oz").len(), 2);
+        assert_eq!(registry.find("XAG").unwrap().disambiguated_symbol, "oz Ag");
+        assert!(CurrencyRegistry::from_toml("[[currencies]]\ncode = 1").is_err());
+    }
+}
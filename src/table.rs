@@ -0,0 +1,386 @@
+use crate::currency::FormattableCurrency;
+use crate::format::{Formatter, Params, Position};
+use crate::{Money, NegativeStyle, Round};
+use std::borrow::Cow;
+use std::fmt;
+
+/// Sides of the amount column that currency symbols can be aligned to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum SymbolAlignment {
+    /// Symbols form a column before the amounts (e.g. `$ 1.00`).
+    Left,
+    /// Symbols form a column after the amounts (e.g. `1.00 $`).
+    Right,
+}
+
+/// Renders a list of Money objects as a column, aligned on the exponent separator.
+///
+/// Amounts may be labelled, and can be mixed with subtotal and total rows which sum the amounts
+/// above them for each currency. Amounts are rounded or padded to `params.rounding` digits, or to the
+/// exponent of their currency if it is not set. Symbols and amounts are written by `Formatter` with `params`, so
+/// they follow its separators, symbol style, bidi and spacing. The positions of `params` are not used, as symbols
+/// and amounts have columns of their own, and columns are aligned on the display width of their text.
+pub struct Table<'a, T: FormattableCurrency> {
+    params: Params,
+    symbol_alignment: SymbolAlignment,
+    rows: Vec<Row<'a, T>>,
+}
+
+/// A row of a Table, before its amounts are formatted.
+enum Row<'a, T: FormattableCurrency> {
+    Amount(Option<String>, Money<'a, T>),
+    Subtotal(String),
+    Total(String),
+}
+
+/// A row of a Table, with its amount formatted and split around the exponent separator.
+struct Line<'t> {
+    label: &'t str,
+    symbol: String,
+    major: String,
+    minor: Option<String>,
+}
+
+impl<'a, T: FormattableCurrency> Table<'a, T> {
+    /// Creates an empty Table which formats amounts using the given parameters.
+    pub fn new(params: Params, symbol_alignment: SymbolAlignment) -> Table<'a, T> {
+        Table {
+            params,
+            symbol_alignment,
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a row with an amount.
+    pub fn push(&mut self, money: Money<'a, T>) {
+        self.rows.push(Row::Amount(None, money));
+    }
+
+    /// Adds a row with a label and an amount.
+    pub fn push_labeled(&mut self, label: &str, money: Money<'a, T>) {
+        self.rows.push(Row::Amount(Some(label.to_string()), money));
+    }
+
+    /// Adds a row for each currency, summing the amounts since the previous subtotal.
+    pub fn subtotal(&mut self, label: &str) {
+        self.rows.push(Row::Subtotal(label.to_string()));
+    }
+
+    /// Adds a row for each currency, summing all amounts above it.
+    pub fn total(&mut self, label: &str) {
+        self.rows.push(Row::Total(label.to_string()));
+    }
+
+    /// Returns the lines of the Table, with subtotals and totals computed.
+    fn lines(&self) -> Vec<Line<'_>> {
+        let mut lines = Vec::new();
+        let mut subtotals: Vec<Money<'a, T>> = Vec::new();
+        let mut totals: Vec<Money<'a, T>> = Vec::new();
+
+        for row in self.rows.iter() {
+            match row {
+                Row::Amount(label, money) => {
+                    Table::accumulate(&mut subtotals, money);
+                    Table::accumulate(&mut totals, money);
+                    lines.push(self.line(label.as_deref().unwrap_or(""), money));
+                }
+                Row::Subtotal(label) => {
+                    for money in subtotals.drain(..) {
+                        lines.push(self.line(label, &money));
+                    }
+                }
+                Row::Total(label) => {
+                    for money in totals.iter() {
+                        lines.push(self.line(label, money));
+                    }
+                }
+            }
+        }
+        lines
+    }
+
+    /// Adds money to the sum of its currency, or starts a new sum if it is the first of its currency.
+    fn accumulate(sums: &mut Vec<Money<'a, T>>, money: &Money<'a, T>) {
+        match sums
+            .iter_mut()
+            .find(|sum| sum.currency() == money.currency())
+        {
+            Some(sum) => *sum += *money,
+            None => sums.push(*money),
+        }
+    }
+
+    /// Formats the amount of a row, and splits it into major and minor units.
+    fn line<'t>(&self, label: &'t str, money: &Money<'a, T>) -> Line<'t> {
+        // Round to the same number of digits, and pad amounts which have fewer.
+        let currency = money.currency();
+        let digits = self
+            .params
+            .rounding
+            .unwrap_or(if currency.has_fixed_exponent() {
+                currency.exponent()
+            } else {
+                money.amount().scale()
+            });
+        let mut amount = *money.round(digits, Round::HalfEven).amount();
+        amount.rescale(digits);
+
+        let money = Money::from_decimal(amount, currency);
+        let params = Params {
+            positions: Cow::Borrowed(&[Position::Sign, Position::Amount]),
+            negative_positions: None,
+            rounding: None,
+            ..self.params.clone()
+        };
+        let amount = Formatter::money(&money, params);
+        let params = Params {
+            positions: Cow::Borrowed(&[Position::Symbol]),
+            negative_positions: None,
+            negative_style: NegativeStyle::Sign,
+            symbol: Some(currency.symbol()),
+            ..self.params.clone()
+        };
+        let symbol = Formatter::money(&money, params);
+
+        let (major, minor) = match amount.split_once(self.params.exponent_separator) {
+            Some((major, minor)) => (major.to_string(), Some(minor.to_string())),
+            None => (amount, None),
+        };
+        Line {
+            label,
+            symbol,
+            major,
+            minor,
+        }
+    }
+}
+
+impl<'a, T: FormattableCurrency> fmt::Display for Table<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = self.lines();
+
+        let width = |column: fn(&Line) -> usize| lines.iter().map(column).max().unwrap_or(0);
+        let label_width = width(|line| display_width(line.label));
+        let symbol_width = width(|line| display_width(&line.symbol));
+        let major_width = width(|line| display_width(&line.major));
+        // The minor units include the exponent separator.
+        let minor_width = width(|line| line.minor.as_ref().map_or(0, |m| display_width(m) + 1));
+        let spacing = self.params.spacing.as_str();
+
+        for (i, line) in lines.iter().enumerate() {
+            let mut row = String::new();
+            if label_width > 0 {
+                row += &pad_end(line.label, label_width);
+                row += " ";
+            }
+            if self.symbol_alignment == SymbolAlignment::Left {
+                row += &pad_end(&line.symbol, symbol_width);
+                row += spacing;
+            }
+
+            row += &" ".repeat(major_width - display_width(&line.major));
+            row += &line.major;
+            let minor = match &line.minor {
+                Some(minor) => format!("{}{}", self.params.exponent_separator, minor),
+                None => String::new(),
+            };
+            row += &pad_end(&minor, minor_width);
+
+            if self.symbol_alignment == SymbolAlignment::Right {
+                row += spacing;
+                row += &line.symbol;
+            }
+
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+/// Pads text with spaces up to a display width.
+fn pad_end(text: &str, width: usize) -> String {
+    format!("{}{}", text, " ".repeat(width - display_width(text)))
+}
+
+/// Returns the number of terminal columns text takes: none for bidi and other formatting characters and combining
+/// marks, two for wide and full-width characters (e.g. ￥ or 円), and one for the others.
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c as u32 {
+            0x0300..=0x036F
+            | 0x0610..=0x061A
+            | 0x061C
+            | 0x064B..=0x065F
+            | 0x200B..=0x200F
+            | 0x202A..=0x202E
+            | 0x2066..=0x2069
+            | 0xFE00..=0xFE0F
+            | 0xFEFF => 0,
+            0x1100..=0x115F
+            | 0x2E80..=0x303E
+            | 0x3041..=0x33FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xA000..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFE30..=0xFE4F
+            | 0xFF00..=0xFF60
+            | 0xFFE0..=0xFFE6
+            | 0x1F300..=0x1F64F
+            | 0x1F900..=0x1F9FF
+            | 0x20000..=0x3FFFD => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{define_currency_set, Direction, Spacing, SymbolStyle};
+
+    define_currency_set!(
+        test {
+            USD: {
+                code: "USD",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 100,
+                name: "USD",
+                symbol: "$",
+                symbol_first: true,
+            },
+            JPY: {
+                code: "JPY",
+                exponent: 0,
+                locale: EnUs,
+                minor_units: 1,
+                name: "Japanese Yen",
+                symbol: "¥",
+                symbol_first: true,
+            },
+            BHD : {
+                code: "BHD",
+                exponent: 3,
+                locale: EnUs,
+                minor_units: 5,
+                name: "Bahraini Dinar",
+                symbol: "ب.د",
+                symbol_first: true,
+            },
+            CNY : {
+                code: "CNY",
+                disambiguated_symbol: "CN¥",
+                exponent: 2,
+                locale: EnUs,
+                minor_units: 1,
+                name: "Chinese Yuan",
+                symbol: "￥",
+                symbol_first: true,
+            }
+        }
+    );
+
+    #[test]
+    fn table_aligns_on_exponent_separator() {
+        let mut table = Table::new(Params::default(), SymbolAlignment::Left);
+        table.push(Money::from_minor(10_000_000, test::USD));
+        table.push(Money::from_minor(-5, test::USD));
+        table.push(Money::from_major(2_500, test::JPY));
+        table.push(Money::from_minor(1_500, test::BHD));
+
+        let expected = [
+            "$   100,000.00",
+            "$        -0.05",
+            "¥     2,500",
+            "ب.د       1.500",
+        ];
+        assert_eq!(expected.join("\n"), table.to_string());
+    }
+
+    #[test]
+    fn table_aligns_symbols_right() {
+        let mut table = Table::new(Params::default(), SymbolAlignment::Right);
+        table.push(Money::from_minor(10_000, test::USD));
+        table.push(Money::from_major(2_500, test::JPY));
+        table.push(Money::from_minor(1_500, test::BHD));
+
+        let expected = ["  100.00  $", "2,500     ¥", "    1.500 ب.د"];
+        assert_eq!(expected.join("\n"), table.to_string());
+    }
+
+    #[test]
+    fn table_uses_params_separators_and_rounding() {
+        let params = Params {
+            digit_separator: '.',
+            exponent_separator: ',',
+            rounding: Some(1),
+            ..Default::default()
+        };
+        let mut table = Table::new(params, SymbolAlignment::Left);
+        table.push(Money::from_minor(123_456, test::USD));
+        table.push(Money::from_major(7, test::JPY));
+
+        let expected = ["$ 1.234,6", "¥     7,0"];
+        assert_eq!(expected.join("\n"), table.to_string());
+    }
+
+    #[test]
+    fn table_computes_subtotals_and_totals_per_currency() {
+        let mut table = Table::new(Params::default(), SymbolAlignment::Left);
+        table.push_labeled("Rent", Money::from_major(1_200, test::USD));
+        table.push_labeled("Food", Money::from_minor(35_025, test::USD));
+        table.subtotal("Home");
+        table.push_labeled("Hotel", Money::from_major(30_000, test::JPY));
+        table.push_labeled("Taxi", Money::from_minor(1_250, test::USD));
+        table.subtotal("Travel");
+        table.total("Total");
+
+        let expected = [
+            "Rent   $  1,200.00",
+            "Food   $    350.25",
+            "Home   $  1,550.25",
+            "Hotel  ¥ 30,000",
+            "Taxi   $     12.50",
+            "Travel ¥ 30,000",
+            "Travel $     12.50",
+            "Total  $  1,562.75",
+            "Total  ¥ 30,000",
+        ];
+        assert_eq!(expected.join("\n"), table.to_string());
+    }
+
+    #[test]
+    fn table_formats_symbols_with_params() {
+        let params = Params {
+            symbol_style: Some(SymbolStyle::Disambiguated),
+            spacing: Spacing::None,
+            ..Default::default()
+        };
+        let mut table = Table::new(params, SymbolAlignment::Left);
+        table.push(Money::from_minor(1_000, test::CNY));
+        table.push(Money::from_minor(100_000, test::USD));
+        assert_eq!(table.to_string(), "CN¥   10.00\n$  1,000.00");
+
+        let params = Params {
+            bidi: Some(Direction::LeftToRight),
+            ..Default::default()
+        };
+        let mut table = Table::new(params, SymbolAlignment::Right);
+        table.push(Money::from_minor(1_500, test::BHD));
+        assert_eq!(table.to_string(), "1.500 \u{2068}ب.د\u{2069}");
+    }
+
+    #[test]
+    fn table_aligns_wide_symbols() {
+        let mut table = Table::new(Params::default(), SymbolAlignment::Left);
+        table.push(Money::from_minor(1_000, test::CNY));
+        table.push(Money::from_minor(100_000, test::USD));
+        assert_eq!(table.to_string(), "￥    10.00\n$  1,000.00");
+        assert_eq!(display_width("\u{200f}￥\u{2069}"), 2);
+    }
+}